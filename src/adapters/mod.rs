@@ -0,0 +1,93 @@
+//! Shared helpers used by strategy adapters when translating strategy
+//! intent into [`StrategyAction`]s for the emulator.
+
+use crate::backtest::emulator::StrategyAction;
+
+/// Sizes an order as a fraction of current account equity rather than a
+/// constant, so results scale with capital: as
+/// [`crate::backtest::emulator::MarketEmulator::balance`] grows, so does
+/// the order size this produces. Returns `0.0` for a non-positive `price`
+/// or `equity` instead of dividing by zero or going short the quote
+/// currency.
+pub fn size_as_fraction_of_equity(equity: f64, fraction: f64, price: f64) -> f64 {
+    if equity <= 0.0 || price <= 0.0 {
+        return 0.0;
+    }
+    (equity * fraction) / price
+}
+
+/// Suppresses a `PlaceBuy`/`PlaceSell` whose expected edge (the move from
+/// `entry_price` to `target_price`, net of a round-trip fee) is below
+/// `min_expected_profit_bps`, so strategies don't churn on trades that are
+/// net-negative after fees. Any other action passes through unchanged.
+pub fn min_profit_filter(
+    action: StrategyAction,
+    entry_price: f64,
+    target_price: f64,
+    round_trip_fee_bps: f64,
+    min_expected_profit_bps: f64,
+) -> Option<StrategyAction> {
+    let move_bps = match &action {
+        StrategyAction::PlaceBuy { .. } => (target_price - entry_price) / entry_price * 10_000.0,
+        StrategyAction::PlaceSell { .. } => (entry_price - target_price) / entry_price * 10_000.0,
+        _ => return Some(action),
+    };
+
+    let expected_profit_bps = move_bps - round_trip_fee_bps;
+    if expected_profit_bps < min_expected_profit_bps {
+        None
+    } else {
+        Some(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::emulator::MarketEmulator;
+
+    #[test]
+    fn order_size_grows_as_equity_grows_over_a_profitable_run() {
+        let mut emulator = MarketEmulator::with_balance(10_000.0);
+        let price = 100.0;
+        let fraction = 0.1;
+
+        let size_before = size_as_fraction_of_equity(emulator.balance(), fraction, price);
+        emulator.apply_pnl(5_000.0);
+        let size_after = size_as_fraction_of_equity(emulator.balance(), fraction, price);
+
+        assert!(size_after > size_before);
+        assert_eq!(size_before, 10.0);
+        assert_eq!(size_after, 15.0);
+    }
+
+    #[test]
+    fn sizing_is_zero_for_non_positive_equity_or_price() {
+        assert_eq!(size_as_fraction_of_equity(0.0, 0.1, 100.0), 0.0);
+        assert_eq!(size_as_fraction_of_equity(10_000.0, 0.1, 0.0), 0.0);
+    }
+
+    fn place_buy() -> StrategyAction {
+        StrategyAction::PlaceBuy {
+            symbol: "BTCUSDT".into(),
+            price: 100.0,
+            size: 1.0,
+        }
+    }
+
+    #[test]
+    fn sub_threshold_edge_is_skipped() {
+        // Entry 100, target 100.05 -> 5bps move, minus 4bps round-trip fee
+        // leaves 1bps, below a 10bps minimum.
+        let filtered = min_profit_filter(place_buy(), 100.0, 100.05, 4.0, 10.0);
+        assert!(filtered.is_none());
+    }
+
+    #[test]
+    fn above_threshold_edge_proceeds() {
+        // Entry 100, target 101 -> 100bps move, minus 4bps fee leaves 96bps,
+        // comfortably above a 10bps minimum.
+        let filtered = min_profit_filter(place_buy(), 100.0, 101.0, 4.0, 10.0);
+        assert!(filtered.is_some());
+    }
+}
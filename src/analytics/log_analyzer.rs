@@ -0,0 +1,100 @@
+//! Parses the bot's textual trade-log lines into structured records, so a
+//! dashboard or alert doesn't have to scrape raw log output itself.
+
+use chrono::{DateTime, Utc};
+
+use crate::models::Side;
+
+/// One trade line extracted from a trade log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTrade {
+    pub closed_at: DateTime<Utc>,
+    pub symbol: String,
+    pub side: Side,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub pnl: f64,
+}
+
+/// Aggregate stats over a batch of [`ParsedTrade`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LogSummary {
+    pub trade_count: usize,
+    pub total_pnl: f64,
+}
+
+/// Parses the bot's trade-log text format into [`ParsedTrade`]s.
+pub struct LogAnalyzer;
+
+impl LogAnalyzer {
+    /// Parses every well-formed trade line out of `text`, one trade per
+    /// line, in the format:
+    /// `<RFC 3339 timestamp> <symbol> <BUY|SELL> entry=<price> exit=<price> pnl=<pnl>`.
+    /// Blank lines, comments, and any other line the bot logs alongside
+    /// trades are skipped rather than failing the whole parse.
+    pub fn parse_trade_log(text: &str) -> Vec<ParsedTrade> {
+        text.lines().filter_map(Self::parse_line).collect()
+    }
+
+    /// Trade count and summed P&L over `trades`.
+    pub fn summarize(trades: &[ParsedTrade]) -> LogSummary {
+        LogSummary { trade_count: trades.len(), total_pnl: trades.iter().map(|t| t.pnl).sum() }
+    }
+
+    fn parse_line(line: &str) -> Option<ParsedTrade> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [timestamp, symbol, side, entry, exit_price, pnl] = fields[..] else { return None };
+
+        let closed_at = DateTime::parse_from_rfc3339(timestamp).ok()?.with_timezone(&Utc);
+        let side = match side {
+            "BUY" => Side::Buy,
+            "SELL" => Side::Sell,
+            _ => return None,
+        };
+        let entry_price = entry.strip_prefix("entry=")?.parse().ok()?;
+        let exit_price = exit_price.strip_prefix("exit=")?.parse().ok()?;
+        let pnl = pnl.strip_prefix("pnl=")?.parse().ok()?;
+
+        Some(ParsedTrade { closed_at, symbol: symbol.to_string(), side, entry_price, exit_price, pnl })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_LOG: &str = "\
+# strategy=channel_split session=asia
+2026-01-01T00:00:00Z BTC_USDT BUY entry=42000.00 exit=42630.00 pnl=630.00
+2026-01-01T01:15:00Z ETH_USDT SELL entry=2500.00 exit=2475.00 pnl=25.00
+not a trade line at all
+2026-01-01T02:30:00Z BTC_USDT SELL entry=42700.00 exit=42900.00 pnl=-200.00
+";
+
+    #[test]
+    fn parse_trade_log_extracts_only_well_formed_trade_lines() {
+        let trades = LogAnalyzer::parse_trade_log(SAMPLE_LOG);
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].symbol, "BTC_USDT");
+        assert_eq!(trades[0].side, Side::Buy);
+        assert!((trades[0].pnl - 630.0).abs() < 1e-9);
+        assert_eq!(trades[2].side, Side::Sell);
+        assert!((trades[2].pnl - (-200.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn summarize_aggregates_count_and_total_pnl() {
+        let trades = LogAnalyzer::parse_trade_log(SAMPLE_LOG);
+
+        let summary = LogAnalyzer::summarize(&trades);
+
+        assert_eq!(summary.trade_count, 3);
+        assert!((summary.total_pnl - 455.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn blank_input_yields_no_trades() {
+        assert!(LogAnalyzer::parse_trade_log("").is_empty());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod log_analyzer;
+pub mod performance;
+pub mod strategy_comparator;
+pub mod trade_analyzer;
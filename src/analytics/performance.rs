@@ -0,0 +1,257 @@
+//! Windowed performance tracking for the live/paper trader, so a dashboard
+//! can show e.g. "last 24h" stats without recomputing from the full history.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+struct ClosedTrade {
+    closed_at: DateTime<Utc>,
+    pnl: f64,
+}
+
+/// Maintains windowed stats over the last `max_trades` trades and/or the
+/// last `max_age` of wall-clock time, updated incrementally as trades close.
+pub struct RollingPerformance {
+    max_trades: Option<usize>,
+    max_age: Option<Duration>,
+    trades: VecDeque<ClosedTrade>,
+}
+
+impl RollingPerformance {
+    pub fn new(max_trades: Option<usize>, max_age: Option<Duration>) -> Self {
+        RollingPerformance {
+            max_trades,
+            max_age,
+            trades: VecDeque::new(),
+        }
+    }
+
+    /// Records a trade closing at `closed_at` with realized `pnl`, then
+    /// evicts anything that has aged out of the window.
+    pub fn on_trade_closed(&mut self, closed_at: DateTime<Utc>, pnl: f64) {
+        self.trades.push_back(ClosedTrade { closed_at, pnl });
+        self.evict_stale(closed_at);
+    }
+
+    fn evict_stale(&mut self, now: DateTime<Utc>) {
+        if let Some(max_age) = self.max_age {
+            while let Some(front) = self.trades.front() {
+                let age = now.signed_duration_since(front.closed_at);
+                if age.to_std().unwrap_or(Duration::ZERO) > max_age {
+                    self.trades.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+        if let Some(max_trades) = self.max_trades {
+            while self.trades.len() > max_trades {
+                self.trades.pop_front();
+            }
+        }
+    }
+
+    pub fn trade_count(&self) -> usize {
+        self.trades.len()
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.trades.is_empty() {
+            return 0.0;
+        }
+        let wins = self.trades.iter().filter(|t| t.pnl > 0.0).count();
+        wins as f64 / self.trades.len() as f64
+    }
+
+    /// Average P&L per trade in the window.
+    pub fn expectancy(&self) -> f64 {
+        if self.trades.is_empty() {
+            return 0.0;
+        }
+        self.trades.iter().map(|t| t.pnl).sum::<f64>() / self.trades.len() as f64
+    }
+
+    /// Max peak-to-trough drawdown of the cumulative P&L within the window.
+    pub fn drawdown(&self) -> f64 {
+        let mut equity = 0.0_f64;
+        let mut peak = 0.0_f64;
+        let mut max_drawdown = 0.0_f64;
+        for trade in &self.trades {
+            equity += trade.pnl;
+            peak = peak.max(equity);
+            max_drawdown = max_drawdown.max(peak - equity);
+        }
+        max_drawdown
+    }
+
+    /// Mean per-trade P&L over its population standard deviation within
+    /// the window — a per-trade Sharpe ratio, since this window has no
+    /// notion of a risk-free rate or a fixed holding period to annualize
+    /// against. `0.0` with fewer than two trades or no variance to divide
+    /// by, rather than dividing by zero.
+    pub fn sharpe_ratio(&self) -> f64 {
+        if self.trades.len() < 2 {
+            return 0.0;
+        }
+        let mean = self.expectancy();
+        let variance =
+            self.trades.iter().map(|t| (t.pnl - mean).powi(2)).sum::<f64>() / self.trades.len() as f64;
+        let std_dev = variance.sqrt();
+        if std_dev == 0.0 {
+            0.0
+        } else {
+            mean / std_dev
+        }
+    }
+}
+
+/// One trailing window's stats from [`rolling_metrics`], ending at
+/// `window_end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowMetric {
+    pub window_end: DateTime<Utc>,
+    /// Equity change over the window as a fraction of the window's starting
+    /// equity.
+    pub return_pct: f64,
+    /// Max peak-to-trough equity decline within the window.
+    pub drawdown: f64,
+    /// Standard deviation of the window's point-to-point equity changes.
+    pub volatility: f64,
+}
+
+/// Computes trailing-window return, drawdown, and volatility at every point
+/// in `equity_curve` that has at least one earlier point within `window` of
+/// it, so a degrading strategy shows up in its most recent windows even if
+/// its all-time stats still look fine. `equity_curve` is assumed sorted by
+/// time, oldest first.
+pub fn rolling_metrics(equity_curve: &[(DateTime<Utc>, f64)], window: Duration) -> Vec<WindowMetric> {
+    let window = chrono::Duration::from_std(window).unwrap_or(chrono::Duration::zero());
+    let mut metrics = Vec::new();
+
+    for (i, &(window_end, equity_end)) in equity_curve.iter().enumerate() {
+        let window_start_time = window_end - window;
+        let points: Vec<(DateTime<Utc>, f64)> =
+            equity_curve[..=i].iter().copied().filter(|&(at, _)| at >= window_start_time).collect();
+        if points.len() < 2 {
+            continue;
+        }
+
+        let equity_start = points[0].1;
+        let return_pct = if equity_start != 0.0 { (equity_end - equity_start) / equity_start } else { 0.0 };
+
+        let mut peak = points[0].1;
+        let mut drawdown = 0.0_f64;
+        for &(_, equity) in &points {
+            peak = peak.max(equity);
+            drawdown = drawdown.max(peak - equity);
+        }
+
+        let changes: Vec<f64> = points.windows(2).map(|pair| pair[1].1 - pair[0].1).collect();
+        let mean = changes.iter().sum::<f64>() / changes.len() as f64;
+        let variance = changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / changes.len() as f64;
+
+        metrics.push(WindowMetric { window_end, return_pct, drawdown, volatility: variance.sqrt() });
+    }
+
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+
+    #[test]
+    fn closing_trades_updates_the_rolling_window() {
+        let mut perf = RollingPerformance::new(None, None);
+        let t0 = Utc::now();
+        perf.on_trade_closed(t0, 10.0);
+        perf.on_trade_closed(t0, -4.0);
+        perf.on_trade_closed(t0, 6.0);
+
+        assert_eq!(perf.trade_count(), 3);
+        assert!((perf.win_rate() - 2.0 / 3.0).abs() < 1e-9);
+        assert!((perf.expectancy() - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn old_trades_age_out_of_the_window() {
+        let mut perf = RollingPerformance::new(None, Some(Duration::from_secs(3600)));
+        let t0 = Utc::now();
+        perf.on_trade_closed(t0 - ChronoDuration::hours(5), 100.0);
+        perf.on_trade_closed(t0, 10.0);
+
+        assert_eq!(perf.trade_count(), 1);
+        assert!((perf.expectancy() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sharpe_ratio_is_positive_for_a_consistently_profitable_series() {
+        let mut perf = RollingPerformance::new(None, None);
+        let t0 = Utc::now();
+        for pnl in [10.0, 8.0, 12.0, 9.0] {
+            perf.on_trade_closed(t0, pnl);
+        }
+        assert!(perf.sharpe_ratio() > 0.0);
+    }
+
+    #[test]
+    fn sharpe_ratio_is_zero_with_fewer_than_two_trades_or_no_variance() {
+        let mut single = RollingPerformance::new(None, None);
+        single.on_trade_closed(Utc::now(), 10.0);
+        assert_eq!(single.sharpe_ratio(), 0.0);
+
+        let mut constant = RollingPerformance::new(None, None);
+        constant.on_trade_closed(Utc::now(), 5.0);
+        constant.on_trade_closed(Utc::now(), 5.0);
+        assert_eq!(constant.sharpe_ratio(), 0.0);
+    }
+
+    #[test]
+    fn max_trades_window_evicts_the_oldest() {
+        let mut perf = RollingPerformance::new(Some(2), None);
+        let t0 = Utc::now();
+        perf.on_trade_closed(t0, 1.0);
+        perf.on_trade_closed(t0, 2.0);
+        perf.on_trade_closed(t0, 3.0);
+
+        assert_eq!(perf.trade_count(), 2);
+        assert!((perf.expectancy() - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rolling_metrics_skips_windows_with_fewer_than_two_points() {
+        let t0 = Utc::now();
+        let curve = vec![(t0, 10_000.0)];
+
+        assert!(rolling_metrics(&curve, Duration::from_secs(3600)).is_empty());
+    }
+
+    #[test]
+    fn rolling_metrics_computes_return_drawdown_and_volatility_per_window() {
+        let t0 = Utc::now();
+        let curve = vec![
+            (t0, 10_000.0),
+            (t0 + ChronoDuration::hours(1), 10_500.0),
+            (t0 + ChronoDuration::hours(2), 10_200.0),
+            (t0 + ChronoDuration::hours(3), 11_000.0),
+        ];
+
+        let metrics = rolling_metrics(&curve, Duration::from_secs(2 * 3600));
+
+        // Windows end at hour 1 (points at 0,1), hour 2 (points at 0,1,2), and
+        // hour 3 (points at 1,2,3 — hour 0 has aged out of the 2h window).
+        assert_eq!(metrics.len(), 3);
+
+        let second_window = metrics[1];
+        assert_eq!(second_window.window_end, t0 + ChronoDuration::hours(2));
+        assert!((second_window.return_pct - (10_200.0 - 10_000.0) / 10_000.0).abs() < 1e-9);
+        assert!((second_window.drawdown - 300.0).abs() < 1e-9);
+
+        let third_window = metrics[2];
+        assert_eq!(third_window.window_end, t0 + ChronoDuration::hours(3));
+        assert!((third_window.return_pct - (11_000.0 - 10_500.0) / 10_500.0).abs() < 1e-9);
+    }
+}
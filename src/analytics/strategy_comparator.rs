@@ -0,0 +1,176 @@
+//! Ranks multiple backtest runs against each other, so the best strategy
+//! isn't decided by eyeballing ROI or drawdown recovery in isolation.
+
+use crate::backtest::metrics::BacktestResult;
+
+/// Weights applied to each axis of [`StrategyComparator::compare`]'s
+/// composite score. Each axis is min-max normalized across the compared
+/// results before weighting, so the weights are comparable regardless of
+/// each axis's natural scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComparisonWeights {
+    pub roi: f64,
+    pub recovery_factor: f64,
+    pub max_drawdown: f64,
+    pub win_rate: f64,
+}
+
+impl Default for ComparisonWeights {
+    fn default() -> Self {
+        ComparisonWeights { roi: 1.0, recovery_factor: 1.0, max_drawdown: 1.0, win_rate: 1.0 }
+    }
+}
+
+/// One [`BacktestResult`]'s position in a [`ComparisonReport`], identified
+/// by its index into the slice passed to [`StrategyComparator::compare`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankedStrategy {
+    pub index: usize,
+    pub score: f64,
+    /// `true` if no other compared result beats this one on every axis at
+    /// once (ROI, recovery factor, and win rate higher-or-equal, drawdown
+    /// lower-or-equal, with at least one strictly better).
+    pub pareto_optimal: bool,
+}
+
+/// [`StrategyComparator::compare`]'s output: every input result ranked by
+/// composite score, highest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    pub ranked: Vec<RankedStrategy>,
+}
+
+/// Ranks a set of [`BacktestResult`]s by a weighted composite of ROI
+/// (`total_pnl`), [`BacktestResult::recovery_factor`],
+/// [`BacktestResult::max_drawdown`], and [`BacktestResult::win_rate`], and
+/// flags which results are Pareto-optimal.
+pub struct StrategyComparator {
+    pub weights: ComparisonWeights,
+}
+
+impl StrategyComparator {
+    pub fn new(weights: ComparisonWeights) -> Self {
+        StrategyComparator { weights }
+    }
+
+    pub fn compare(&self, results: &[BacktestResult]) -> ComparisonReport {
+        let axes: Vec<Axes> = results.iter().map(Axes::from).collect();
+
+        let roi_range = min_max(axes.iter().map(|a| a.roi));
+        let recovery_factor_range = min_max(axes.iter().map(|a| a.recovery_factor));
+        let drawdown_range = min_max(axes.iter().map(|a| a.max_drawdown));
+        let win_rate_range = min_max(axes.iter().map(|a| a.win_rate));
+
+        let mut ranked: Vec<RankedStrategy> = axes
+            .iter()
+            .enumerate()
+            .map(|(index, this)| {
+                let score = self.weights.roi * normalize(this.roi, roi_range)
+                    + self.weights.recovery_factor * normalize(this.recovery_factor, recovery_factor_range)
+                    + self.weights.max_drawdown * (1.0 - normalize(this.max_drawdown, drawdown_range))
+                    + self.weights.win_rate * normalize(this.win_rate, win_rate_range);
+                let pareto_optimal = !axes
+                    .iter()
+                    .enumerate()
+                    .any(|(other_index, other)| other_index != index && other.dominates(this));
+                RankedStrategy { index, score, pareto_optimal }
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        ComparisonReport { ranked }
+    }
+}
+
+/// The four axes [`StrategyComparator`] compares a [`BacktestResult`] on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Axes {
+    roi: f64,
+    recovery_factor: f64,
+    max_drawdown: f64,
+    win_rate: f64,
+}
+
+impl From<&BacktestResult> for Axes {
+    fn from(result: &BacktestResult) -> Self {
+        Axes {
+            roi: result.total_pnl,
+            recovery_factor: result.recovery_factor(),
+            max_drawdown: result.max_drawdown,
+            win_rate: result.win_rate(),
+        }
+    }
+}
+
+impl Axes {
+    /// `true` if `self` is at least as good as `other` on every axis and
+    /// strictly better on at least one.
+    fn dominates(&self, other: &Axes) -> bool {
+        let at_least_as_good = self.roi >= other.roi
+            && self.recovery_factor >= other.recovery_factor
+            && self.max_drawdown <= other.max_drawdown
+            && self.win_rate >= other.win_rate;
+        let strictly_better = self.roi > other.roi
+            || self.recovery_factor > other.recovery_factor
+            || self.max_drawdown < other.max_drawdown
+            || self.win_rate > other.win_rate;
+        at_least_as_good && strictly_better
+    }
+}
+
+fn min_max(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::MAX, f64::MIN), |(min, max), v| (min.min(v), max.max(v)))
+}
+
+/// `0.5` when `min == max`, since there's no range to place `value` within.
+fn normalize(value: f64, (min, max): (f64, f64)) -> f64 {
+    if max > min {
+        (value - min) / (max - min)
+    } else {
+        0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(total_pnl: f64, max_drawdown: f64, winning_trades: usize, total_trades: usize) -> BacktestResult {
+        BacktestResult {
+            total_trades,
+            winning_trades,
+            total_pnl,
+            max_drawdown,
+            ..BacktestResult::default()
+        }
+    }
+
+    #[test]
+    fn a_dominated_result_ranks_below_the_result_that_dominates_it_and_is_not_pareto_optimal() {
+        // A beats B on every axis: more P&L, smaller drawdown, higher win rate.
+        let a = result(1000.0, 100.0, 6, 10);
+        let b = result(500.0, 200.0, 4, 10);
+        let comparator = StrategyComparator::new(ComparisonWeights::default());
+
+        let report = comparator.compare(&[a, b]);
+
+        let a_rank = report.ranked.iter().position(|r| r.index == 0).unwrap();
+        let b_rank = report.ranked.iter().position(|r| r.index == 1).unwrap();
+        assert!(a_rank < b_rank, "the dominating result should rank first");
+        assert!(report.ranked[a_rank].pareto_optimal);
+        assert!(!report.ranked[b_rank].pareto_optimal);
+    }
+
+    #[test]
+    fn trade_off_results_are_both_pareto_optimal() {
+        // A: more P&L but a bigger drawdown and lower win rate than C — neither
+        // dominates the other.
+        let a = result(1500.0, 300.0, 5, 10);
+        let c = result(1000.0, 100.0, 6, 10);
+        let comparator = StrategyComparator::new(ComparisonWeights::default());
+
+        let report = comparator.compare(&[a, c]);
+
+        assert!(report.ranked.iter().all(|r| r.pareto_optimal));
+    }
+}
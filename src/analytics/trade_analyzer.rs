@@ -0,0 +1,113 @@
+//! Diagnoses exit quality by measuring how far price moved against and in
+//! favor of a trade while it was open, rather than only looking at its
+//! final realized P&L.
+
+use chrono::{DateTime, Utc};
+
+use crate::backtest::metrics::{Side, Trade};
+
+/// Maximum Adverse/Favorable Excursion for one [`Trade`], in price terms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeExcursion {
+    /// Worst unrealized loss seen while the trade was open — a small MAE
+    /// relative to the stop distance means the stop has room to spare; a
+    /// large one means it was nearly hit.
+    pub mae: f64,
+    /// Best unrealized gain seen while the trade was open — an MFE well
+    /// above the realized P&L means profit was left on the table by
+    /// exiting too early or the target sitting too close.
+    pub mfe: f64,
+}
+
+/// Computes per-trade excursion reports from a strategy's trade log and its
+/// intratrade price path.
+pub struct TradeAnalyzer;
+
+impl TradeAnalyzer {
+    /// Computes [`TradeExcursion`] for every trade in `trades`, using only
+    /// the `price_path` samples that fall within
+    /// `[trade.opened_at, trade.closed_at]`. A trade with no samples in its
+    /// own window reports `0.0` for both.
+    pub fn mae_mfe(trades: &[Trade], price_path: &[(DateTime<Utc>, f64)]) -> Vec<TradeExcursion> {
+        trades
+            .iter()
+            .map(|trade| {
+                let mut mae = 0.0_f64;
+                let mut mfe = 0.0_f64;
+                for &(at, price) in price_path {
+                    if at < trade.opened_at || at > trade.closed_at {
+                        continue;
+                    }
+                    let unrealized = match trade.side {
+                        Side::Buy => price - trade.entry_price,
+                        Side::Sell => trade.entry_price - price,
+                    };
+                    if unrealized < 0.0 {
+                        mae = mae.max(-unrealized);
+                    } else {
+                        mfe = mfe.max(unrealized);
+                    }
+                }
+                TradeExcursion { mae, mfe }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(minute: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(minute)
+    }
+
+    fn trade(side: Side, entry_price: f64, exit_price: f64) -> Trade {
+        Trade {
+            symbol: "BTC_USDT".to_string(),
+            side,
+            entry_price,
+            exit_price,
+            size: 1.0,
+            pnl: 0.0,
+            fees: 0.0,
+            opened_at: at(0),
+            closed_at: at(3),
+            strategy_id: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn a_long_trade_reports_the_dip_below_and_rise_above_entry() {
+        let trades = vec![trade(Side::Buy, 100.0, 103.0)];
+        let price_path = vec![(at(0), 100.0), (at(1), 97.0), (at(2), 104.0), (at(3), 103.0)];
+
+        let excursions = TradeAnalyzer::mae_mfe(&trades, &price_path);
+
+        assert!((excursions[0].mae - 3.0).abs() < 1e-9);
+        assert!((excursions[0].mfe - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn a_short_trade_measures_excursion_against_the_mirrored_direction() {
+        let trades = vec![trade(Side::Sell, 100.0, 98.0)];
+        let price_path = vec![(at(0), 100.0), (at(1), 102.0), (at(2), 97.0), (at(3), 98.0)];
+
+        let excursions = TradeAnalyzer::mae_mfe(&trades, &price_path);
+
+        assert!((excursions[0].mae - 2.0).abs() < 1e-9);
+        assert!((excursions[0].mfe - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn samples_outside_the_trade_window_are_ignored() {
+        let trades = vec![trade(Side::Buy, 100.0, 103.0)];
+        let price_path = vec![(at(-5), 500.0), (at(0), 100.0), (at(3), 103.0), (at(10), -500.0)];
+
+        let excursions = TradeAnalyzer::mae_mfe(&trades, &price_path);
+
+        assert_eq!(excursions[0].mae, 0.0);
+        assert!((excursions[0].mfe - 3.0).abs() < 1e-9);
+    }
+}
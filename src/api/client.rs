@@ -0,0 +1,111 @@
+//! A [`super::ExchangeAPI`] implementation against Gate.io's public ticker
+//! endpoint. The actual HTTP call is injected as a
+//! `Fn(&str) -> Result<String, String>` fetcher, the same pattern
+//! [`crate::exchanges::gate::GateWsGateway`] uses for its socket, so the
+//! decoding and validation logic stays unit-testable without a real
+//! network call.
+
+use serde::Deserialize;
+
+use super::{ExchangeAPI, OrderId, OrderRequest};
+
+const TICKER_ENDPOINT: &str = "https://api.gateio.ws/api/v4/spot/tickers";
+
+/// A REST client for Gate.io, parameterized over its HTTP fetcher so tests
+/// can inject a fixture response instead of hitting the network.
+pub struct RestExchangeClient<F: Fn(&str) -> Result<String, String>> {
+    fetch: F,
+    api_key: Option<String>,
+}
+
+impl<F: Fn(&str) -> Result<String, String>> RestExchangeClient<F> {
+    /// Starts with no credentials configured. There is no default or
+    /// fallback API key baked in here; [`RestExchangeClient::with_credentials`]
+    /// is the only way to enable order placement.
+    pub fn new(fetch: F) -> Self {
+        RestExchangeClient { fetch, api_key: None }
+    }
+
+    /// Configures trading credentials. Without them, [`ExchangeAPI::place_order`]
+    /// always fails before attempting anything.
+    pub fn with_credentials(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+}
+
+impl<F: Fn(&str) -> Result<String, String>> ExchangeAPI for RestExchangeClient<F> {
+    fn get_price(&self, symbol: &str) -> Result<f64, String> {
+        let url = format!("{TICKER_ENDPOINT}?currency_pair={symbol}");
+        let body = (self.fetch)(&url)?;
+        parse_last_price(&body)
+    }
+
+    fn place_order(&self, order: OrderRequest) -> Result<OrderId, String> {
+        order.validate()?;
+        if self.api_key.is_none() {
+            return Err("cannot place an order: no API credentials configured".to_string());
+        }
+        Err("live order placement is not implemented".to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerRow {
+    last: String,
+}
+
+fn parse_last_price(raw: &str) -> Result<f64, String> {
+    let rows: Vec<TickerRow> = serde_json::from_str(raw).map_err(|e| format!("invalid Gate ticker response: {e}"))?;
+    let row = rows.first().ok_or_else(|| "empty Gate ticker response".to_string())?;
+    row.last.parse::<f64>().map_err(|e| format!("invalid Gate ticker price: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Side;
+
+    #[test]
+    fn get_price_parses_the_last_field_of_the_first_ticker_row() {
+        let client = RestExchangeClient::new(|_url: &str| {
+            Ok(r#"[{"currency_pair":"BTC_USDT","last":"64251.5"}]"#.to_string())
+        });
+        assert_eq!(client.get_price("BTC_USDT"), Ok(64251.5));
+    }
+
+    #[test]
+    fn get_price_propagates_a_fetch_error() {
+        let client = RestExchangeClient::new(|_url: &str| Err("connection refused".to_string()));
+        assert_eq!(client.get_price("BTC_USDT"), Err("connection refused".to_string()));
+    }
+
+    #[test]
+    fn get_price_rejects_an_empty_ticker_response() {
+        let client = RestExchangeClient::new(|_url: &str| Ok("[]".to_string()));
+        assert!(client.get_price("BTC_USDT").is_err());
+    }
+
+    #[test]
+    fn place_order_rejects_an_invalid_request_before_checking_credentials() {
+        let client = RestExchangeClient::new(|_url: &str| Ok("[]".to_string()));
+        let order = OrderRequest { symbol: "".to_string(), amount: 1.0, side: Side::Buy };
+        assert!(client.place_order(order).is_err());
+    }
+
+    #[test]
+    fn place_order_fails_without_configured_credentials() {
+        let client = RestExchangeClient::new(|_url: &str| Ok("[]".to_string()));
+        let order = OrderRequest { symbol: "BTC_USDT".to_string(), amount: 1.0, side: Side::Buy };
+        let err = client.place_order(order).unwrap_err();
+        assert!(err.contains("credentials"));
+    }
+
+    #[test]
+    fn place_order_with_credentials_still_reports_it_is_unimplemented() {
+        let client = RestExchangeClient::new(|_url: &str| Ok("[]".to_string())).with_credentials("key".to_string());
+        let order = OrderRequest { symbol: "BTC_USDT".to_string(), amount: 1.0, side: Side::Buy };
+        let err = client.place_order(order).unwrap_err();
+        assert!(err.contains("not implemented"));
+    }
+}
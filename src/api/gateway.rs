@@ -0,0 +1,87 @@
+//! Fronts an [`ExchangeAPI`] with optional client-side rate limiting.
+
+use std::sync::Mutex;
+
+use super::rate_limit::RateLimiter;
+use super::{ExchangeAPI, OrderId, OrderRequest};
+
+/// Wraps `inner`, optionally pacing every call through a [`RateLimiter`].
+///
+/// There's no concept of login attempts or account lockout here — the
+/// closest analogue to "back off after repeated failures" in this crate is
+/// [`crate::exchanges::gate::run_listener_with_reconnect`]'s escalating
+/// reconnect backoff, which paces retries after a dropped connection the
+/// same way this gateway paces calls after a busy bucket.
+pub struct Gateway<A: ExchangeAPI> {
+    inner: A,
+    limiter: Option<Mutex<RateLimiter>>,
+}
+
+impl<A: ExchangeAPI> Gateway<A> {
+    pub fn new(inner: A) -> Self {
+        Gateway { inner, limiter: None }
+    }
+
+    /// Paces every call through this gateway to at most `per_sec` a second.
+    pub fn with_rate_limit(mut self, per_sec: u32) -> Self {
+        self.limiter = Some(Mutex::new(RateLimiter::new(per_sec)));
+        self
+    }
+
+    /// How many calls had to wait for a token, or 0 if no rate limit was
+    /// configured.
+    pub fn throttled_count(&self) -> u64 {
+        self.limiter.as_ref().map(|limiter| limiter.lock().unwrap().throttled_count()).unwrap_or(0)
+    }
+
+    fn throttle(&self) {
+        if let Some(limiter) = &self.limiter {
+            limiter.lock().unwrap().acquire();
+        }
+    }
+}
+
+impl<A: ExchangeAPI> ExchangeAPI for Gateway<A> {
+    fn get_price(&self, symbol: &str) -> Result<f64, String> {
+        self.throttle();
+        self.inner.get_price(symbol)
+    }
+
+    fn place_order(&self, order: OrderRequest) -> Result<OrderId, String> {
+        self.throttle();
+        self.inner.place_order(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::RestExchangeClient;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn unrated_gateway_forwards_calls_without_pacing() {
+        let client = RestExchangeClient::new(|_url: &str| Ok(r#"[{"last":"100.0"}]"#.to_string()));
+        let gateway = Gateway::new(client);
+        assert_eq!(gateway.get_price("BTC_USDT"), Ok(100.0));
+        assert_eq!(gateway.throttled_count(), 0);
+    }
+
+    #[test]
+    fn n_requests_take_at_least_the_rate_limits_minimum_wall_time() {
+        let client = RestExchangeClient::new(|_url: &str| Ok(r#"[{"last":"100.0"}]"#.to_string()));
+        let gateway = Gateway::new(client).with_rate_limit(1000);
+
+        let requests = 5;
+        let start = Instant::now();
+        for _ in 0..requests {
+            gateway.get_price("BTC_USDT").unwrap();
+        }
+        let elapsed = start.elapsed();
+
+        // The first call is free; the remaining (requests - 1) each cost
+        // at least one 1ms token interval at 1000/sec.
+        assert!(elapsed >= Duration::from_millis((requests - 1) as u64));
+        assert_eq!(gateway.throttled_count(), requests as u64 - 1);
+    }
+}
@@ -0,0 +1,73 @@
+//! A minimal exchange REST API surface: price lookups and order
+//! placement, kept separate from the websocket venue handlers in
+//! [`crate::exchanges`] so a strategy can depend on a narrow trait instead
+//! of a specific venue's wire format.
+
+pub mod client;
+pub mod gateway;
+pub mod rate_limit;
+
+pub use client::RestExchangeClient;
+pub use gateway::Gateway;
+
+use crate::models::Side;
+
+/// What a strategy or adapter needs from an exchange over REST.
+/// [`RestExchangeClient`] implements this against Gate.io; other venues
+/// can add their own implementation without touching call sites.
+///
+/// The `api_key` credentials an implementation holds authenticate against
+/// the exchange, not against this crate's own caller — there's no user
+/// account or role concept here to gate access by.
+pub trait ExchangeAPI {
+    fn get_price(&self, symbol: &str) -> Result<f64, String>;
+    fn place_order(&self, order: OrderRequest) -> Result<OrderId, String>;
+}
+
+/// The exchange's identifier for a placed order.
+pub type OrderId = String;
+
+/// An order placement request, validated before it's sent anywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub amount: f64,
+    pub side: Side,
+}
+
+impl OrderRequest {
+    /// Rejects an empty symbol or a non-positive amount. `side` needs no
+    /// check of its own: [`Side`] only has `Buy`/`Sell` variants.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.symbol.trim().is_empty() {
+            return Err("order symbol must not be empty".to_string());
+        }
+        if self.amount <= 0.0 {
+            return Err(format!("order amount must be positive, got {}", self.amount));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_an_empty_symbol() {
+        let order = OrderRequest { symbol: "  ".to_string(), amount: 1.0, side: Side::Buy };
+        assert!(order.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_a_non_positive_amount() {
+        let order = OrderRequest { symbol: "BTC_USDT".to_string(), amount: 0.0, side: Side::Sell };
+        assert!(order.validate().is_err());
+    }
+
+    #[test]
+    fn validate_accepts_a_well_formed_request() {
+        let order = OrderRequest { symbol: "BTC_USDT".to_string(), amount: 0.5, side: Side::Buy };
+        assert_eq!(order.validate(), Ok(()));
+    }
+}
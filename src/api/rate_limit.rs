@@ -0,0 +1,79 @@
+//! A token-bucket rate limiter for outgoing REST calls, so a burst of
+//! requests doesn't trip an exchange's own rate limit. This crate has no
+//! async runtime, so it blocks the calling thread via `std::thread::sleep`
+//! rather than awaiting.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Allows one call immediately, then paces further calls to `per_sec` a
+/// second, sleeping out any deficit.
+pub struct RateLimiter {
+    per_sec: u32,
+    tokens: f64,
+    last_refill: Instant,
+    throttled: u64,
+}
+
+impl RateLimiter {
+    pub fn new(per_sec: u32) -> Self {
+        RateLimiter {
+            per_sec: per_sec.max(1),
+            tokens: 1.0,
+            last_refill: Instant::now(),
+            throttled: 0,
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_sec as f64).min(1.0);
+        self.last_refill = now;
+    }
+
+    /// Blocks the calling thread until a token is available, then consumes
+    /// one.
+    pub fn acquire(&mut self) {
+        self.refill();
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return;
+        }
+
+        self.throttled += 1;
+        let deficit = 1.0 - self.tokens;
+        thread::sleep(Duration::from_secs_f64(deficit / self.per_sec as f64));
+        self.refill();
+        self.tokens = (self.tokens - 1.0).max(0.0);
+    }
+
+    /// How many [`RateLimiter::acquire`] calls had to wait for a token.
+    pub fn throttled_count(&self) -> u64 {
+        self.throttled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_acquire_does_not_block() {
+        let mut limiter = RateLimiter::new(10);
+        let start = Instant::now();
+        limiter.acquire();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(limiter.throttled_count(), 0);
+    }
+
+    #[test]
+    fn an_empty_bucket_blocks_and_counts_as_throttled() {
+        let mut limiter = RateLimiter::new(1000);
+        limiter.acquire();
+        let start = Instant::now();
+        limiter.acquire();
+        assert!(start.elapsed() >= Duration::from_millis(1));
+        assert_eq!(limiter.throttled_count(), 1);
+    }
+}
@@ -0,0 +1,933 @@
+//! A compact fixed-layout binary format for recorded trade ticks
+//! (`.bin` files), used for fast backtest data loading.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::models::{Side, TradeTick};
+
+/// Integer scale applied to prices before storage, so files store exact
+/// fixed-point integers instead of lossy `f64` bit patterns.
+pub const PRICE_SCALE: i64 = 100_000_000;
+/// Integer scale applied to trade sizes before storage.
+pub const QTY_SCALE: i64 = 100_000_000;
+
+/// Record length for format version 1 and earlier: `ts_ns + price_scaled +
+/// size_scaled + side`, with no best bid/ask.
+const RECORD_LEN_V1: usize = 8 + 8 + 8 + 1;
+/// Record length for format version 2: [`RECORD_LEN_V1`] plus a scaled
+/// best bid and best ask, each `i64::MIN` when absent.
+const RECORD_LEN_V2: usize = RECORD_LEN_V1 + 8 + 8;
+/// One sparse-index entry: a record's timestamp and its byte offset in the
+/// `.bin` file, `8 + 8` bytes.
+const INDEX_ENTRY_LEN: usize = 8 + 8;
+/// Sentinel scaled price meaning "absent" for [`TradeRecord::best_bid_scaled`]/
+/// [`TradeRecord::best_ask_scaled`], since a real scaled price is never
+/// negative.
+const NO_PRICE: i64 = i64::MIN;
+
+/// The sidecar sparse-index path for a `.bin` file: its path with `.idx`
+/// appended, so `trades.bin` indexes to `trades.bin.idx`.
+fn index_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".idx");
+    PathBuf::from(name)
+}
+
+/// Identifies a file as this crate's `.bin` trade format, so an unrelated
+/// or corrupted file is rejected instead of silently misread.
+const MAGIC: [u8; 4] = *b"RTBF";
+/// The format version [`BinFileWriter`] writes and [`BinFileReader`]
+/// expects. A file with no [`MAGIC`] at all predates the header and is
+/// treated as version `0` for backward compatibility; version `1` predates
+/// [`TradeRecord::best_bid_scaled`]/[`TradeRecord::best_ask_scaled`]; version
+/// `2` predates the one-byte flags field [`BinFileWriter::with_compression`]
+/// sets.
+const FORMAT_VERSION: u16 = 3;
+/// `magic (4) + version (2)`.
+const HEADER_LEN: usize = 6;
+/// The flags byte that follows [`HEADER_LEN`] for version `3` and later
+/// files. Only [`FLAG_COMPRESSED`] is defined so far.
+const FLAGS_LEN: usize = 1;
+/// Set in a version-3+ file's flags byte when the record body (everything
+/// between the header and the CRC32 trailer) is [`packbits_encode`]d.
+const FLAG_COMPRESSED: u8 = 0b0000_0001;
+
+const CRC32_POLY: u32 = 0xEDB8_8320;
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ CRC32_POLY } else { crc >> 1 };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// Folds `bytes` into a running CRC32 (IEEE 802.3) state. Start `crc` at
+/// `0xFFFF_FFFF` and XOR the final result with `0xFFFF_FFFF` to get the
+/// standard checksum value, as [`crc32`] does for a one-shot computation.
+fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = crc;
+    for &byte in bytes {
+        let index = ((crc ^ byte as u32) & 0xFF) as usize;
+        crc = CRC32_TABLE[index] ^ (crc >> 8);
+    }
+    crc
+}
+
+/// Compresses `data` with the PackBits run-length scheme (the same one TIFF
+/// uses): each chunk is a count byte followed by either a literal span or a
+/// single repeated byte. Trade records are full of repeated bytes (zero
+/// padding, the constant [`NO_PRICE`] sentinel, a handful of distinct
+/// `side` values), so this is cheap to compute and gives most of the win of
+/// a general-purpose compressor without pulling one in as a dependency.
+fn packbits_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let mut run = 1;
+        while i + run < data.len() && run < 128 && data[i + run] == data[i] {
+            run += 1;
+        }
+        if run >= 2 {
+            out.push((1 - run as i32) as u8);
+            out.push(data[i]);
+            i += run;
+            continue;
+        }
+
+        let start = i;
+        let mut len = 1;
+        i += 1;
+        while i < data.len() && len < 128 {
+            let mut next_run = 1;
+            while i + next_run < data.len() && data[i + next_run] == data[i] {
+                next_run += 1;
+            }
+            if next_run >= 2 {
+                break;
+            }
+            len += 1;
+            i += 1;
+        }
+        out.push((len - 1) as u8);
+        out.extend_from_slice(&data[start..start + len]);
+    }
+    out
+}
+
+/// Reads one version-appropriate record off `r`, or `Ok(None)` at EOF.
+/// Shared by [`BinFileReader::read_record`]'s streamed and decompressed
+/// paths, which differ only in what `r` is.
+fn decode_next<R: Read>(r: &mut R, version: u16) -> io::Result<Option<TradeRecord>> {
+    if version <= 1 {
+        let mut buf = [0u8; RECORD_LEN_V1];
+        return match r.read_exact(&mut buf) {
+            Ok(()) => Ok(Some(TradeRecord::decode_v1(&buf))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(e),
+        };
+    }
+    let mut buf = [0u8; RECORD_LEN_V2];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(Some(TradeRecord::decode_v2(&buf))),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Decompresses a [`packbits_encode`]d buffer back to its original bytes.
+fn packbits_decode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let count = data[i] as i8;
+        i += 1;
+        if count >= 0 {
+            let len = count as usize + 1;
+            out.extend_from_slice(&data[i..i + len]);
+            i += len;
+        } else if count != -128 {
+            let len = (1 - count as isize) as usize;
+            out.extend(std::iter::repeat_n(data[i], len));
+            i += 1;
+        }
+    }
+    out
+}
+
+/// One on-disk trade record: a scaled, fixed-point mirror of [`TradeTick`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TradeRecord {
+    pub ts_ns: i64,
+    pub price_scaled: i64,
+    pub size_scaled: i64,
+    pub side: Side,
+    /// The best bid at the time of this trade, scaled by [`PRICE_SCALE`].
+    /// Persisted from [`TradeTick::best_bid`] starting at format version 2
+    /// (absent, and always `None` on decode, for an older file).
+    pub best_bid_scaled: Option<i64>,
+    /// The best ask at the time of this trade, scaled by [`PRICE_SCALE`].
+    /// See [`TradeRecord::best_bid_scaled`].
+    pub best_ask_scaled: Option<i64>,
+}
+
+impl TradeRecord {
+    pub fn from_tick(tick: &TradeTick) -> Self {
+        TradeRecord {
+            ts_ns: tick.ts_ns,
+            price_scaled: (tick.price * PRICE_SCALE as f64).round() as i64,
+            size_scaled: (tick.size * QTY_SCALE as f64).round() as i64,
+            side: tick.side,
+            best_bid_scaled: tick.best_bid.map(|p| (p * PRICE_SCALE as f64).round() as i64),
+            best_ask_scaled: tick.best_ask.map(|p| (p * PRICE_SCALE as f64).round() as i64),
+        }
+    }
+
+    pub fn to_tick(&self) -> TradeTick {
+        TradeTick {
+            ts_ns: self.ts_ns,
+            price: self.price_scaled as f64 / PRICE_SCALE as f64,
+            size: self.size_scaled as f64 / QTY_SCALE as f64,
+            side: self.side,
+            best_bid: self.best_bid_scaled.map(|p| p as f64 / PRICE_SCALE as f64),
+            best_ask: self.best_ask_scaled.map(|p| p as f64 / PRICE_SCALE as f64),
+        }
+    }
+
+    /// Encodes this record in the current ([`RECORD_LEN_V2`]) layout,
+    /// [`NO_PRICE`] standing in for an absent best bid/ask.
+    fn encode(&self) -> [u8; RECORD_LEN_V2] {
+        let mut buf = [0u8; RECORD_LEN_V2];
+        buf[0..8].copy_from_slice(&self.ts_ns.to_le_bytes());
+        buf[8..16].copy_from_slice(&self.price_scaled.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.size_scaled.to_le_bytes());
+        buf[24] = match self.side {
+            Side::Buy => 0,
+            Side::Sell => 1,
+        };
+        buf[25..33].copy_from_slice(&self.best_bid_scaled.unwrap_or(NO_PRICE).to_le_bytes());
+        buf[33..41].copy_from_slice(&self.best_ask_scaled.unwrap_or(NO_PRICE).to_le_bytes());
+        buf
+    }
+
+    /// Decodes a [`RECORD_LEN_V1`]-byte record (format version 0 or 1),
+    /// which has no best bid/ask.
+    fn decode_v1(buf: &[u8; RECORD_LEN_V1]) -> Self {
+        let ts_ns = i64::from_le_bytes(buf[0..8].try_into().unwrap());
+        let price_scaled = i64::from_le_bytes(buf[8..16].try_into().unwrap());
+        let size_scaled = i64::from_le_bytes(buf[16..24].try_into().unwrap());
+        let side = if buf[24] == 0 { Side::Buy } else { Side::Sell };
+        TradeRecord { ts_ns, price_scaled, size_scaled, side, best_bid_scaled: None, best_ask_scaled: None }
+    }
+
+    /// Decodes a [`RECORD_LEN_V2`]-byte record (format version 2 or later).
+    fn decode_v2(buf: &[u8; RECORD_LEN_V2]) -> Self {
+        let mut head = [0u8; RECORD_LEN_V1];
+        head.copy_from_slice(&buf[0..RECORD_LEN_V1]);
+        let mut record = Self::decode_v1(&head);
+        let best_bid_scaled = i64::from_le_bytes(buf[25..33].try_into().unwrap());
+        let best_ask_scaled = i64::from_le_bytes(buf[33..41].try_into().unwrap());
+        record.best_bid_scaled = (best_bid_scaled != NO_PRICE).then_some(best_bid_scaled);
+        record.best_ask_scaled = (best_ask_scaled != NO_PRICE).then_some(best_ask_scaled);
+        record
+    }
+}
+
+/// When a [`BinFileWriter`] flushes on its own, rather than waiting for an
+/// explicit [`BinFileWriter::flush`] call. Lets long-running live capture
+/// bound how much unflushed data a crash could lose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlushPolicy {
+    /// Flush after every `n` records.
+    EveryRecords(usize),
+    /// Flush once at least `interval` has passed since the last flush,
+    /// checked on the next `write_record` call (there's no background
+    /// timer).
+    EveryInterval(Duration),
+    /// Only flush when told to.
+    Manual,
+}
+
+/// Appends [`TradeRecord`]s to a `.bin` file, buffering in memory between
+/// flushes per its [`FlushPolicy`] so a crash loses at most the unflushed
+/// tail rather than the whole run.
+pub struct BinFileWriter {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    policy: FlushPolicy,
+    fsync: bool,
+    records_since_flush: usize,
+    last_flush: Instant,
+    bytes_written: u64,
+    records_written: usize,
+    /// Write a sidecar sparse index if set, recording a `(ts_ns, offset)`
+    /// entry every `n`th record. Ignored when [`BinFileWriter::compressed`]
+    /// is set, since a compressed file can't be seeked into.
+    index_interval: Option<usize>,
+    index_entries: Vec<(i64, u64)>,
+    /// Running CRC32 over every record's encoded bytes, finalized and
+    /// appended as a trailer by [`BinFileWriter::finish`].
+    crc: u32,
+    compressed: bool,
+    /// The record body, buffered until [`BinFileWriter::finish`] so it can
+    /// be [`packbits_encode`]d as one block. Only used when `compressed`.
+    pending: Vec<u8>,
+    header_written: bool,
+}
+
+impl BinFileWriter {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::create(path.as_ref())?;
+        Ok(BinFileWriter {
+            writer: BufWriter::new(file),
+            path: path.as_ref().to_path_buf(),
+            policy: FlushPolicy::Manual,
+            fsync: false,
+            records_since_flush: 0,
+            last_flush: Instant::now(),
+            bytes_written: 0,
+            records_written: 0,
+            index_interval: None,
+            index_entries: Vec::new(),
+            crc: 0xFFFF_FFFF,
+            compressed: false,
+            pending: Vec::new(),
+            header_written: false,
+        })
+    }
+
+    /// Builds a sidecar sparse index (written on [`BinFileWriter::finish`])
+    /// recording a `(timestamp, byte offset)` entry every `every_n_records`
+    /// records, so [`BinFileReader::seek_to_time`] can jump near a target
+    /// timestamp instead of scanning the whole file.
+    pub fn with_sparse_index(mut self, every_n_records: usize) -> Self {
+        self.index_interval = Some(every_n_records.max(1));
+        self
+    }
+
+    /// Sets when this writer flushes on its own, instead of only on an
+    /// explicit [`BinFileWriter::flush`] call.
+    pub fn with_flush_policy(mut self, policy: FlushPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Has every flush also `fsync` the file, so a flushed record survives
+    /// a crash (not just a process exit) at the cost of a slower flush.
+    pub fn with_fsync(mut self, fsync: bool) -> Self {
+        self.fsync = fsync;
+        self
+    }
+
+    /// Compresses the record body with [`packbits_encode`] before writing
+    /// it, trading a larger memory footprint (every record is buffered
+    /// until [`BinFileWriter::finish`]) and no [`BinFileReader::seek_to_time`]
+    /// support for a smaller file on disk.
+    pub fn with_compression(mut self, compressed: bool) -> Self {
+        self.compressed = compressed;
+        self
+    }
+
+    fn ensure_header_written(&mut self) -> io::Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        self.writer.write_all(&MAGIC)?;
+        self.writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        let flags = if self.compressed { FLAG_COMPRESSED } else { 0 };
+        self.writer.write_all(&[flags])?;
+        self.bytes_written = (HEADER_LEN + FLAGS_LEN) as u64;
+        self.header_written = true;
+        Ok(())
+    }
+
+    pub fn write_record(&mut self, record: &TradeRecord) -> io::Result<()> {
+        self.ensure_header_written()?;
+        let encoded = record.encode();
+        self.crc = crc32_update(self.crc, &encoded);
+        if self.compressed {
+            self.pending.extend_from_slice(&encoded);
+        } else {
+            if let Some(interval) = self.index_interval {
+                if self.records_written.is_multiple_of(interval) {
+                    self.index_entries.push((record.ts_ns, self.bytes_written));
+                }
+            }
+            self.writer.write_all(&encoded)?;
+            self.bytes_written += RECORD_LEN_V2 as u64;
+        }
+        self.records_written += 1;
+        self.records_since_flush += 1;
+        if self.due_for_flush() {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn due_for_flush(&self) -> bool {
+        match self.policy {
+            FlushPolicy::EveryRecords(n) => self.records_since_flush >= n,
+            FlushPolicy::EveryInterval(interval) => self.last_flush.elapsed() >= interval,
+            FlushPolicy::Manual => false,
+        }
+    }
+
+    /// Flushes the in-memory buffer to the OS, and `fsync`s it to disk if
+    /// [`BinFileWriter::with_fsync`] was set.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        if self.fsync {
+            self.writer.get_ref().sync_all()?;
+        }
+        self.records_since_flush = 0;
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+
+    /// Flushes the remaining buffer, appends a CRC32 trailer over every
+    /// record written (verified by [`BinFileReader::read_all_verified`]),
+    /// and, if [`BinFileWriter::with_sparse_index`] was set, writes the
+    /// sidecar sparse index alongside the `.bin` file. Consumes `self`
+    /// since there's nothing useful left to write to once the trailer and
+    /// index (keyed off the final byte offsets) have been committed.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.ensure_header_written()?;
+        if self.compressed {
+            let body = packbits_encode(&self.pending);
+            self.writer.write_all(&body)?;
+        }
+        self.flush()?;
+        self.writer.write_all(&(self.crc ^ 0xFFFF_FFFF).to_le_bytes())?;
+        self.writer.flush()?;
+        if self.index_interval.is_some() && !self.compressed {
+            let mut index_file = BufWriter::new(File::create(index_path(&self.path))?);
+            for (ts_ns, offset) in &self.index_entries {
+                index_file.write_all(&ts_ns.to_le_bytes())?;
+                index_file.write_all(&offset.to_le_bytes())?;
+            }
+            index_file.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads [`TradeRecord`]s sequentially from a `.bin` file.
+pub struct BinFileReader {
+    reader: BufReader<File>,
+    path: PathBuf,
+    /// The format version read from the file's header, or `0` for a
+    /// headerless file predating [`MAGIC`].
+    version: u16,
+    /// The byte offset the first record starts at: `0` for a headerless
+    /// file, [`HEADER_LEN`] (plus [`FLAGS_LEN`] from version `3`) once a
+    /// [`MAGIC`] header was read and skipped. Meaningless for a compressed
+    /// file, whose records all come from `inflated` instead.
+    data_start: u64,
+    /// The whole record body, already [`packbits_decode`]d, for a file
+    /// written with [`BinFileWriter::with_compression`]. `None` for an
+    /// uncompressed file, which is instead read directly off `reader`.
+    inflated: Option<Cursor<Vec<u8>>>,
+    /// The CRC32 trailer of a compressed file, read eagerly at
+    /// [`BinFileReader::open`] time since the whole body had to be read
+    /// into memory anyway to decompress it.
+    inflated_trailer: Option<u32>,
+}
+
+impl BinFileReader {
+    /// Opens `path`, validating and skipping past its [`MAGIC`] header if
+    /// present. A file with no header at all is accepted as version `0`
+    /// for backward compatibility; a file with a header whose version is
+    /// newer than [`FORMAT_VERSION`] is rejected, since this crate has no
+    /// decoder for it. Every version up to and including [`FORMAT_VERSION`]
+    /// is still readable (see [`TradeRecord::decode_v1`]). A version `3`+
+    /// file with [`FLAG_COMPRESSED`] set has its body decompressed here.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0u8; 4];
+        let (version, data_start, compressed) = match reader.read_exact(&mut magic) {
+            Ok(()) if magic == MAGIC => {
+                let mut version_buf = [0u8; 2];
+                reader.read_exact(&mut version_buf)?;
+                let version = u16::from_le_bytes(version_buf);
+                if version > FORMAT_VERSION {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unsupported .bin format version {version}, newer than {FORMAT_VERSION}"),
+                    ));
+                }
+                if version >= 3 {
+                    let mut flags_buf = [0u8; FLAGS_LEN];
+                    reader.read_exact(&mut flags_buf)?;
+                    let compressed = flags_buf[0] & FLAG_COMPRESSED != 0;
+                    (version, (HEADER_LEN + FLAGS_LEN) as u64, compressed)
+                } else {
+                    (version, HEADER_LEN as u64, false)
+                }
+            }
+            Ok(()) => {
+                // First 4 bytes aren't our magic: a headerless (version 0)
+                // file. Rewind so record reads start at byte 0.
+                reader.seek(SeekFrom::Start(0))?;
+                (0, 0, false)
+            }
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                // Too short to even hold a 4-byte magic: also version 0.
+                reader.seek(SeekFrom::Start(0))?;
+                (0, 0, false)
+            }
+            Err(e) => return Err(e),
+        };
+
+        let (inflated, inflated_trailer) = if compressed {
+            let mut rest = Vec::new();
+            reader.read_to_end(&mut rest)?;
+            if rest.len() < 4 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "compressed .bin file is truncated: shorter than its CRC32 trailer",
+                ));
+            }
+            let split_at = rest.len() - 4;
+            let trailer = u32::from_le_bytes(rest[split_at..].try_into().unwrap());
+            let body = packbits_decode(&rest[..split_at]);
+            (Some(Cursor::new(body)), Some(trailer))
+        } else {
+            (None, None)
+        };
+
+        Ok(BinFileReader { reader, path: path.as_ref().to_path_buf(), version, data_start, inflated, inflated_trailer })
+    }
+
+    /// Reads the next record, or `Ok(None)` at end of file.
+    pub fn read_record(&mut self) -> io::Result<Option<TradeRecord>> {
+        if let Some(cursor) = &mut self.inflated {
+            return decode_next(cursor, self.version);
+        }
+        decode_next(&mut self.reader, self.version)
+    }
+
+    pub fn read_all(&mut self) -> io::Result<Vec<TradeRecord>> {
+        let mut records = Vec::new();
+        while let Some(record) = self.read_record()? {
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+    /// Like [`BinFileReader::read_all`], but also verifies the CRC32
+    /// trailer [`BinFileWriter::finish`] appends, returning an error if the
+    /// file's contents don't match it. A version `0` (headerless) file has
+    /// no trailer to check, so this degrades to [`BinFileReader::read_all`]
+    /// for one.
+    pub fn read_all_verified(&mut self) -> io::Result<Vec<TradeRecord>> {
+        if self.version == 0 {
+            return self.read_all();
+        }
+
+        if let Some(cursor) = &mut self.inflated {
+            cursor.set_position(0);
+            let actual = crc32_update(0xFFFF_FFFF, cursor.get_ref()) ^ 0xFFFF_FFFF;
+            let expected = self.inflated_trailer.expect("compressed file always has a trailer");
+            if actual != expected {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "compressed .bin file's checksum doesn't match its contents",
+                ));
+            }
+            return self.read_all();
+        }
+
+        let file_len = self.reader.get_ref().metadata()?.len();
+        let data_end = file_len.saturating_sub(4);
+
+        let mut crc = 0xFFFF_FFFF_u32;
+        let mut records = Vec::new();
+        loop {
+            if self.reader.stream_position()? >= data_end {
+                break;
+            }
+            if self.version <= 1 {
+                let mut buf = [0u8; RECORD_LEN_V1];
+                self.reader.read_exact(&mut buf)?;
+                crc = crc32_update(crc, &buf);
+                records.push(TradeRecord::decode_v1(&buf));
+            } else {
+                let mut buf = [0u8; RECORD_LEN_V2];
+                self.reader.read_exact(&mut buf)?;
+                crc = crc32_update(crc, &buf);
+                records.push(TradeRecord::decode_v2(&buf));
+            }
+        }
+
+        let mut trailer = [0u8; 4];
+        self.reader.read_exact(&mut trailer)?;
+        let expected = u32::from_le_bytes(trailer);
+        let actual = crc ^ 0xFFFF_FFFF;
+        if actual != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("checksum mismatch: file trailer says {expected:#010x}, computed {actual:#010x}"),
+            ));
+        }
+
+        Ok(records)
+    }
+
+    /// Positions the reader so the next [`BinFileReader::read_record`]
+    /// returns the first record at or after `ts_ns`. Uses the sidecar
+    /// sparse index written by [`BinFileWriter::with_sparse_index`], if one
+    /// exists alongside this file, to jump near the target before
+    /// finishing with a short linear scan; falls back to scanning the
+    /// whole file from the start if no index is present, so this is always
+    /// correct, just slower without an index. Not supported for a file
+    /// written with [`BinFileWriter::with_compression`], since there's no
+    /// byte-offset correspondence between the compressed file and its
+    /// decompressed records to seek into.
+    pub fn seek_to_time(&mut self, ts_ns: i64) -> io::Result<()> {
+        if self.inflated.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "seek_to_time isn't supported on a compressed .bin file",
+            ));
+        }
+        let start_offset = self.index_offset_for(ts_ns)?;
+        self.reader.seek(SeekFrom::Start(start_offset))?;
+
+        loop {
+            let before = self.reader.stream_position()?;
+            match self.read_record()? {
+                Some(record) if record.ts_ns >= ts_ns => {
+                    self.reader.seek(SeekFrom::Start(before))?;
+                    break;
+                }
+                Some(_) => continue,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// The byte offset of the last sidecar index entry at or before
+    /// `ts_ns`, or [`BinFileReader::data_start`] if no sidecar index file
+    /// exists or every entry is after `ts_ns`.
+    fn index_offset_for(&self, ts_ns: i64) -> io::Result<u64> {
+        let mut index_file = match File::open(index_path(&self.path)) {
+            Ok(file) => BufReader::new(file),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(self.data_start),
+            Err(e) => return Err(e),
+        };
+
+        let mut best_offset = self.data_start;
+        let mut buf = [0u8; INDEX_ENTRY_LEN];
+        loop {
+            match index_file.read_exact(&mut buf) {
+                Ok(()) => {
+                    let entry_ts = i64::from_le_bytes(buf[0..8].try_into().unwrap());
+                    let entry_offset = u64::from_le_bytes(buf[8..16].try_into().unwrap());
+                    if entry_ts <= ts_ns {
+                        best_offset = entry_offset;
+                    } else {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(best_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, AtomicOrdering::Relaxed);
+        std::env::temp_dir().join(format!("rust-trade-test-{name}-{}-{n}.bin", std::process::id()))
+    }
+
+    #[test]
+    fn round_trips_records_through_a_file() {
+        let path = scratch_path("round-trip");
+        let records = vec![
+            TradeRecord {
+                ts_ns: 1_000,
+                price_scaled: 5_000_000_000_000,
+                size_scaled: 100_000_000,
+                side: Side::Buy,
+                ..Default::default()
+            },
+            TradeRecord {
+                ts_ns: 2_000,
+                price_scaled: 5_001_000_000_000,
+                size_scaled: 50_000_000,
+                side: Side::Sell,
+                ..Default::default()
+            },
+        ];
+
+        let mut writer = BinFileWriter::create(&path).unwrap();
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let read_back = reader.read_all().unwrap();
+        assert_eq!(read_back, records);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_flush_every_k_policy_makes_k_records_visible_without_an_explicit_flush() {
+        let path = scratch_path("flush-every-k");
+        let mut writer = BinFileWriter::create(&path).unwrap().with_flush_policy(FlushPolicy::EveryRecords(2));
+
+        let record = TradeRecord {
+            ts_ns: 1,
+            price_scaled: 1_000_000_000,
+            size_scaled: 100_000_000,
+            side: Side::Buy,
+            ..Default::default()
+        };
+
+        writer.write_record(&record).unwrap(); // buffered, not yet flushed
+        let mut reader = BinFileReader::open(&path).unwrap();
+        assert_eq!(reader.read_all().unwrap().len(), 0);
+
+        writer.write_record(&record).unwrap(); // 2nd record triggers the auto-flush
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        assert_eq!(reader.read_all().unwrap().len(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    fn record_at(ts_ns: i64) -> TradeRecord {
+        TradeRecord {
+            ts_ns,
+            price_scaled: 1_000_000_000,
+            size_scaled: 100_000_000,
+            side: Side::Buy,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn seek_to_time_uses_the_sparse_index_to_jump_near_a_mid_file_timestamp() {
+        let path = scratch_path("seek-with-index");
+        let mut writer = BinFileWriter::create(&path).unwrap().with_sparse_index(2);
+        for ts in (0..10).map(|i| i * 1_000) {
+            writer.write_record(&record_at(ts)).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        reader.seek_to_time(5_500).unwrap();
+        let record = reader.read_record().unwrap().unwrap();
+        assert_eq!(record.ts_ns, 6_000);
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(index_path(&path)).unwrap();
+    }
+
+    #[test]
+    fn seek_to_time_falls_back_to_a_linear_scan_without_an_index() {
+        let path = scratch_path("seek-without-index");
+        let mut writer = BinFileWriter::create(&path).unwrap();
+        for ts in (0..5).map(|i| i * 1_000) {
+            writer.write_record(&record_at(ts)).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        reader.seek_to_time(2_500).unwrap();
+        let record = reader.read_record().unwrap().unwrap();
+        assert_eq!(record.ts_ns, 3_000);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_headerless_file_is_read_as_version_0_without_error() {
+        let path = scratch_path("headerless");
+        std::fs::write(&path, record_at(1_000).encode()).unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let records = reader.read_all().unwrap();
+        assert_eq!(records, vec![record_at(1_000)]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_header_with_an_unsupported_version_is_rejected() {
+        let path = scratch_path("bad-version");
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&99u16.to_le_bytes());
+        bytes.extend_from_slice(&record_at(1_000).encode());
+        std::fs::write(&path, bytes).unwrap();
+
+        let err = match BinFileReader::open(&path) {
+            Ok(_) => panic!("expected an error opening a file with an unsupported version"),
+            Err(e) => e,
+        };
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_all_verified_accepts_a_file_with_a_matching_checksum() {
+        let path = scratch_path("checksum-ok");
+        let mut writer = BinFileWriter::create(&path).unwrap();
+        writer.write_record(&record_at(1_000)).unwrap();
+        writer.write_record(&record_at(2_000)).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let records = reader.read_all_verified().unwrap();
+        assert_eq!(records, vec![record_at(1_000), record_at(2_000)]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_all_verified_rejects_a_file_with_a_corrupted_record() {
+        let path = scratch_path("checksum-corrupted");
+        let mut writer = BinFileWriter::create(&path).unwrap();
+        writer.write_record(&record_at(1_000)).unwrap();
+        writer.finish().unwrap();
+
+        // Flip a byte inside the one record, after the header, leaving the
+        // trailer's checksum stale.
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[HEADER_LEN + FLAGS_LEN] ^= 0xFF;
+        std::fs::write(&path, &bytes).unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let err = reader.read_all_verified().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn from_tick_and_to_tick_round_trip_through_the_fixed_point_scale() {
+        let tick = TradeTick {
+            ts_ns: 42,
+            price: 50_123.456,
+            size: 0.875,
+            side: Side::Sell,
+            best_bid: Some(50_123.0),
+            best_ask: Some(50_124.0),
+        };
+
+        let record = TradeRecord::from_tick(&tick);
+        let round_tripped = record.to_tick();
+
+        assert!((round_tripped.price - tick.price).abs() < 1e-6);
+        assert!((round_tripped.size - tick.size).abs() < 1e-6);
+        assert_eq!(round_tripped.side, tick.side);
+        assert_eq!(round_tripped.ts_ns, tick.ts_ns);
+        assert!((round_tripped.best_bid.unwrap() - tick.best_bid.unwrap()).abs() < 1e-6);
+        assert!((round_tripped.best_ask.unwrap() - tick.best_ask.unwrap()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn round_trips_records_with_and_without_best_bid_ask_through_a_file() {
+        let path = scratch_path("round-trip-bid-ask");
+        let with_quotes = TradeRecord {
+            ts_ns: 1_000,
+            price_scaled: 5_000_000_000_000,
+            size_scaled: 100_000_000,
+            side: Side::Buy,
+            best_bid_scaled: Some(4_999_000_000_000),
+            best_ask_scaled: Some(5_001_000_000_000),
+        };
+        let without_quotes = record_at(2_000);
+        assert_eq!(without_quotes.best_bid_scaled, None);
+        assert_eq!(without_quotes.best_ask_scaled, None);
+
+        let mut writer = BinFileWriter::create(&path).unwrap();
+        writer.write_record(&with_quotes).unwrap();
+        writer.write_record(&without_quotes).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let records = reader.read_all().unwrap();
+        assert_eq!(records, vec![with_quotes, without_quotes]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_compressed_file_round_trips_the_same_records_as_an_uncompressed_one() {
+        let path = scratch_path("compressed");
+        let records: Vec<_> = (0..20).map(|i| record_at(i * 1_000)).collect();
+
+        let mut writer = BinFileWriter::create(&path).unwrap().with_compression(true);
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        assert_eq!(reader.read_all_verified().unwrap(), records);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_truncated_compressed_file_is_rejected_instead_of_panicking() {
+        let path = scratch_path("compressed-truncated");
+        // A well-formed compressed header with a body shorter than the
+        // CRC32 trailer it promises: too little to even hold the checksum.
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.push(FLAG_COMPRESSED);
+        bytes.extend_from_slice(&[0u8; 2]);
+        std::fs::write(&path, &bytes).unwrap();
+
+        let err = match BinFileReader::open(&path) {
+            Ok(_) => panic!("expected an error opening a truncated compressed file"),
+            Err(e) => e,
+        };
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_compressed_file_rejects_seek_to_time() {
+        let path = scratch_path("compressed-seek");
+        let mut writer = BinFileWriter::create(&path).unwrap().with_compression(true);
+        writer.write_record(&record_at(1_000)).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = BinFileReader::open(&path).unwrap();
+        let err = reader.seek_to_time(1_000).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+//! Commission/fee accounting. Fees on USDT-settled contracts reduce the
+//! USDT balance directly; fees on coin-margined contracts are charged in
+//! the underlying coin and must be converted to the account currency.
+
+/// The currency a venue denominates its trading fee in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeCurrency {
+    /// Fee is charged directly in USDT (e.g. USDT-settled perpetuals).
+    Usdt,
+    /// Fee is charged in the base coin (e.g. coin-margined contracts) and
+    /// must be converted to USDT at the fill price.
+    Coin,
+}
+
+/// A flat-rate commission model.
+#[derive(Debug, Clone, Copy)]
+pub struct CommissionModel {
+    /// Fee rate as a fraction of notional (e.g. `0.0005` for 5bps).
+    pub rate: f64,
+    pub fee_currency: FeeCurrency,
+}
+
+impl CommissionModel {
+    pub fn new(rate: f64, fee_currency: FeeCurrency) -> Self {
+        CommissionModel { rate, fee_currency }
+    }
+
+    /// Computes the fee for a fill of `size` contracts at `fill_price`,
+    /// returned in USDT regardless of `fee_currency`.
+    pub fn fee_in_usdt(&self, size: f64, fill_price: f64) -> f64 {
+        let notional = size * fill_price;
+        match self.fee_currency {
+            FeeCurrency::Usdt => notional * self.rate,
+            FeeCurrency::Coin => {
+                // The fee is charged in coin against the position size, then
+                // converted to USDT at the current fill price.
+                let fee_coin = size * self.rate;
+                fee_coin * fill_price
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usdt_settled_fee_reduces_usdt_directly() {
+        let commission = CommissionModel::new(0.0005, FeeCurrency::Usdt);
+        let fee = commission.fee_in_usdt(2.0, 100.0);
+        assert!((fee - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn coin_margined_fee_is_converted_to_usdt_using_the_fill_price() {
+        let commission = CommissionModel::new(0.0005, FeeCurrency::Coin);
+        // 2 contracts * 0.0005 rate = 0.001 coin fee, converted at $100/coin.
+        let fee = commission.fee_in_usdt(2.0, 100.0);
+        assert!((fee - 0.1).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,528 @@
+//! A minimal order emulator for the backtest loop: strategies submit
+//! intent, the emulator owns the resting orders and is the source of truth
+//! for order ids.
+
+use std::collections::HashMap;
+
+pub type OrderId = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub id: OrderId,
+    pub symbol: String,
+    pub side: Side,
+    pub price: f64,
+    pub size: f64,
+    /// Index of the strategy that owns this order, used to reject another
+    /// strategy's replace/cancel against it.
+    pub owner: usize,
+    /// Liquidity still ahead of this order in the queue at its price,
+    /// tracked via [`MarketEmulator::record_trade_at`] when
+    /// [`EmulatorSettings::use_queue_model`] is set. `0.0` (immediately
+    /// fillable once price touches it) for an order placed via
+    /// [`MarketEmulator::place_order`], or for any order placed while the
+    /// queue model is off.
+    pub queue_ahead: f64,
+}
+
+/// Tunable behavior for [`MarketEmulator`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EmulatorSettings {
+    /// When set, a resting limit order placed via
+    /// [`MarketEmulator::place_limit_order`] only becomes fillable once the
+    /// cumulative volume traded through its price reaches the liquidity
+    /// that was resting ahead of it when it was placed. When unset, queue
+    /// priority is ignored and an order is fillable as soon as price
+    /// touches it.
+    pub use_queue_model: bool,
+    /// Fee rate charged on a resting (maker) limit order's fill, as a
+    /// fraction of notional. Negative for a maker rebate.
+    pub maker_fee: f64,
+    /// Fee rate charged on a marketable (taker) fill, as a fraction of
+    /// notional.
+    pub taker_fee: f64,
+}
+
+/// What a strategy wants to happen this tick. Replace/cancel target a
+/// specific `order_id` rather than "an active order for this symbol", so a
+/// strategy managing several orders doesn't clobber the wrong one.
+#[derive(Debug, Clone)]
+pub enum StrategyAction {
+    PlaceBuy { symbol: String, price: f64, size: f64 },
+    PlaceSell { symbol: String, price: f64, size: f64 },
+    ReplaceBuy { order_id: OrderId, new_price: f64 },
+    CancelOrder { order_id: OrderId },
+}
+
+#[derive(Default)]
+pub struct MarketEmulator {
+    orders: HashMap<OrderId, Order>,
+    next_id: OrderId,
+    balance: f64,
+    settings: EmulatorSettings,
+    total_fees: f64,
+}
+
+impl MarketEmulator {
+    pub fn new() -> Self {
+        MarketEmulator {
+            orders: HashMap::new(),
+            next_id: 1,
+            balance: 0.0,
+            settings: EmulatorSettings::default(),
+            total_fees: 0.0,
+        }
+    }
+
+    /// Starts the emulator with `balance` already on the account, so
+    /// balance-fraction position sizing has something to size against from
+    /// the first tick.
+    pub fn with_balance(balance: f64) -> Self {
+        MarketEmulator { balance, ..MarketEmulator::new() }
+    }
+
+    /// Starts the emulator with `settings` instead of the defaults.
+    pub fn with_settings(settings: EmulatorSettings) -> Self {
+        MarketEmulator { settings, ..MarketEmulator::new() }
+    }
+
+    pub fn balance(&self) -> f64 {
+        self.balance
+    }
+
+    /// Applies a closed trade's P&L to the account balance, so later
+    /// balance-fraction sizing reflects the run's gains and losses so far.
+    pub fn apply_pnl(&mut self, pnl: f64) {
+        self.balance += pnl;
+    }
+
+    pub fn place_order(&mut self, owner: usize, symbol: String, side: Side, price: f64, size: f64) -> OrderId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.orders.insert(
+            id,
+            Order {
+                id,
+                symbol,
+                side,
+                price,
+                size,
+                owner,
+                queue_ahead: 0.0,
+            },
+        );
+        id
+    }
+
+    /// Places a resting limit order with `liquidity_ahead` resting ahead
+    /// of it at `price` (e.g. from an [`crate::backtest::orderbook::OrderBook`]
+    /// snapshot taken at placement time). If
+    /// [`EmulatorSettings::use_queue_model`] is unset, `liquidity_ahead` is
+    /// ignored and the order behaves like one placed via
+    /// [`MarketEmulator::place_order`].
+    pub fn place_limit_order(
+        &mut self,
+        owner: usize,
+        symbol: String,
+        side: Side,
+        price: f64,
+        size: f64,
+        liquidity_ahead: f64,
+    ) -> OrderId {
+        let id = self.place_order(owner, symbol, side, price, size);
+        if self.settings.use_queue_model {
+            self.orders.get_mut(&id).unwrap().queue_ahead = liquidity_ahead;
+        }
+        id
+    }
+
+    /// Advances queue priority for every resting order at `price` by
+    /// `traded_volume`, so a maker strategy's fill timing reflects how much
+    /// volume had to trade through before its order's turn came up.
+    /// Returns the ids of orders now fillable (their `queue_ahead` has
+    /// dropped to `0.0`).
+    pub fn record_trade_at(&mut self, price: f64, traded_volume: f64) -> Vec<OrderId> {
+        let mut now_fillable = Vec::new();
+        for order in self.orders.values_mut().filter(|order| order.price == price) {
+            if order.queue_ahead > 0.0 {
+                order.queue_ahead = (order.queue_ahead - traded_volume).max(0.0);
+            }
+            if order.queue_ahead == 0.0 {
+                now_fillable.push(order.id);
+            }
+        }
+        now_fillable
+    }
+
+    /// Whether `order_id` has no queue liquidity left ahead of it (or
+    /// doesn't exist, which is equally not fillable).
+    pub fn is_fillable(&self, order_id: OrderId) -> bool {
+        self.orders.get(&order_id).is_some_and(|order| order.queue_ahead <= 0.0)
+    }
+
+    /// Replaces the price of `order_id`, rejecting the request if the order
+    /// doesn't exist or doesn't belong to `owner`.
+    pub fn replace_buy(&mut self, owner: usize, order_id: OrderId, new_price: f64) -> Result<(), String> {
+        let order = self
+            .orders
+            .get_mut(&order_id)
+            .ok_or_else(|| format!("no such order {order_id}"))?;
+        if order.owner != owner {
+            return Err(format!("order {order_id} is not owned by strategy {owner}"));
+        }
+        order.price = new_price;
+        Ok(())
+    }
+
+    /// Cancels `order_id`, rejecting the request if it doesn't exist or
+    /// doesn't belong to `owner`.
+    pub fn cancel_order(&mut self, owner: usize, order_id: OrderId) -> Result<(), String> {
+        match self.orders.get(&order_id) {
+            Some(order) if order.owner == owner => {
+                self.orders.remove(&order_id);
+                Ok(())
+            }
+            Some(_) => Err(format!("order {order_id} is not owned by strategy {owner}")),
+            None => Err(format!("no such order {order_id}")),
+        }
+    }
+
+    pub fn order(&self, order_id: OrderId) -> Option<&Order> {
+        self.orders.get(&order_id)
+    }
+
+    pub fn active_orders_for(&self, owner: usize) -> Vec<&Order> {
+        self.orders.values().filter(|o| o.owner == owner).collect()
+    }
+
+    /// Fills `fill_size` of `order_id` at `fill_price`, shrinking it or, if
+    /// that fills it completely, removing it. Charges
+    /// [`EmulatorSettings::maker_fee`] if `is_maker` (a resting order's
+    /// fill) or [`EmulatorSettings::taker_fee`] otherwise (a marketable
+    /// fill), accumulating it into [`MarketEmulator::total_fees`]. Returns
+    /// the resulting [`OrderUpdate`], or an error if the order doesn't
+    /// exist.
+    pub fn fill_order(
+        &mut self,
+        order_id: OrderId,
+        fill_price: f64,
+        fill_size: f64,
+        is_maker: bool,
+    ) -> Result<OrderUpdate, String> {
+        let order = self.orders.get_mut(&order_id).ok_or_else(|| format!("no such order {order_id}"))?;
+        let remaining_size = (order.size - fill_size).max(0.0);
+        order.size = remaining_size;
+        if remaining_size <= 0.0 {
+            self.orders.remove(&order_id);
+        }
+
+        let rate = if is_maker { self.settings.maker_fee } else { self.settings.taker_fee };
+        let fee = fill_price * fill_size * rate;
+        self.total_fees += fee;
+
+        Ok(OrderUpdate::Filled { order_id, fill_price, fill_size, remaining_size, fee })
+    }
+
+    /// The sum of every fee charged by [`MarketEmulator::fill_order`] so
+    /// far, positive for fees paid and negative for net maker rebates
+    /// received.
+    pub fn total_fees(&self) -> f64 {
+        self.total_fees
+    }
+}
+
+/// An order-lifecycle event delivered to the owning strategy via
+/// [`Strategy::on_order_update`], for reacting to partial fills and cancels
+/// rather than just the ticks that led to them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderUpdate {
+    /// `fill_size` of `order_id` was filled at `fill_price`; `remaining_size`
+    /// is what's left resting, `0.0` once the order is fully filled. `fee`
+    /// is the maker/taker fee charged on this fill (negative for a maker
+    /// rebate).
+    Filled { order_id: OrderId, fill_price: f64, fill_size: f64, remaining_size: f64, fee: f64 },
+    Cancelled { order_id: OrderId },
+}
+
+/// Fills `fill_size` of `order_id` against `emulator` and notifies its
+/// owning strategy via [`Strategy::on_order_update`]. Errors (and notifies
+/// no one) if the order doesn't exist.
+pub fn apply_fill(
+    strategies: &mut [Box<dyn Strategy>],
+    emulator: &mut MarketEmulator,
+    order_id: OrderId,
+    fill_price: f64,
+    fill_size: f64,
+    is_maker: bool,
+) -> Result<(), String> {
+    let owner = emulator.order(order_id).ok_or_else(|| format!("no such order {order_id}"))?.owner;
+    let update = emulator.fill_order(order_id, fill_price, fill_size, is_maker)?;
+    strategies[owner].on_order_update(update);
+    Ok(())
+}
+
+/// Cancels `order_id` against `emulator` on behalf of `owner` and notifies
+/// it via [`Strategy::on_order_update`]. Errors (and notifies no one) if
+/// the order doesn't exist or isn't owned by `owner`.
+pub fn apply_cancel(
+    strategies: &mut [Box<dyn Strategy>],
+    emulator: &mut MarketEmulator,
+    owner: usize,
+    order_id: OrderId,
+) -> Result<(), String> {
+    emulator.cancel_order(owner, order_id)?;
+    strategies[owner].on_order_update(OrderUpdate::Cancelled { order_id });
+    Ok(())
+}
+
+/// A strategy that tracks its own order ids (as returned by the emulator on
+/// placement) so it can target specific orders for replace/cancel.
+pub trait Strategy {
+    /// Returns this tick's desired actions.
+    fn actions(&mut self) -> Vec<StrategyAction>;
+    /// Called once per `PlaceBuy`/`PlaceSell` action with the id the
+    /// emulator assigned, so the strategy can remember it for later
+    /// `ReplaceBuy`/`CancelOrder` actions.
+    fn on_order_placed(&mut self, order_id: OrderId);
+    /// Called on every fill or cancel affecting one of this strategy's
+    /// orders, via [`apply_fill`]/[`apply_cancel`]. The default no-op is
+    /// correct for a strategy that only cares about completed trades, not
+    /// the fills building up to one.
+    fn on_order_update(&mut self, _update: OrderUpdate) {}
+}
+
+/// Runs one recalculation pass: asks each strategy for its actions and
+/// applies them against `emulator`, feeding back newly assigned order ids.
+pub fn recalculate_strategies(strategies: &mut [Box<dyn Strategy>], emulator: &mut MarketEmulator) {
+    for (owner, strategy) in strategies.iter_mut().enumerate() {
+        for action in strategy.actions() {
+            match action {
+                StrategyAction::PlaceBuy { symbol, price, size } => {
+                    let id = emulator.place_order(owner, symbol, Side::Buy, price, size);
+                    strategy.on_order_placed(id);
+                }
+                StrategyAction::PlaceSell { symbol, price, size } => {
+                    let id = emulator.place_order(owner, symbol, Side::Sell, price, size);
+                    strategy.on_order_placed(id);
+                }
+                StrategyAction::ReplaceBuy { order_id, new_price } => {
+                    let _ = emulator.replace_buy(owner, order_id, new_price);
+                }
+                StrategyAction::CancelOrder { order_id } => {
+                    let _ = emulator.cancel_order(owner, order_id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct ScriptedStrategy {
+        queued: Vec<StrategyAction>,
+        order_ids: Rc<RefCell<Vec<OrderId>>>,
+    }
+
+    impl Strategy for ScriptedStrategy {
+        fn actions(&mut self) -> Vec<StrategyAction> {
+            std::mem::take(&mut self.queued)
+        }
+
+        fn on_order_placed(&mut self, order_id: OrderId) {
+            self.order_ids.borrow_mut().push(order_id);
+        }
+    }
+
+    /// Does nothing on its own turn; only records the [`OrderUpdate`]s it's
+    /// notified of.
+    struct NotifiedStrategy {
+        updates: Rc<RefCell<Vec<OrderUpdate>>>,
+    }
+
+    impl Strategy for NotifiedStrategy {
+        fn actions(&mut self) -> Vec<StrategyAction> {
+            Vec::new()
+        }
+
+        fn on_order_placed(&mut self, _order_id: OrderId) {}
+
+        fn on_order_update(&mut self, update: OrderUpdate) {
+            self.updates.borrow_mut().push(update);
+        }
+    }
+
+    #[test]
+    fn a_partial_fill_notifies_the_owning_strategy_with_the_remaining_size() {
+        let mut emulator = MarketEmulator::new();
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 3.0);
+        let updates = Rc::new(RefCell::new(Vec::new()));
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![Box::new(NotifiedStrategy { updates: updates.clone() })];
+
+        apply_fill(&mut strategies, &mut emulator, id, 100.0, 1.0, false).unwrap();
+
+        assert_eq!(
+            updates.borrow()[0],
+            OrderUpdate::Filled { order_id: id, fill_price: 100.0, fill_size: 1.0, remaining_size: 2.0, fee: 0.0 }
+        );
+        assert_eq!(emulator.order(id).unwrap().size, 2.0);
+    }
+
+    #[test]
+    fn a_full_fill_removes_the_order_and_notifies_zero_remaining() {
+        let mut emulator = MarketEmulator::new();
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 2.0);
+        let updates = Rc::new(RefCell::new(Vec::new()));
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![Box::new(NotifiedStrategy { updates: updates.clone() })];
+
+        apply_fill(&mut strategies, &mut emulator, id, 100.0, 2.0, false).unwrap();
+
+        assert_eq!(
+            updates.borrow()[0],
+            OrderUpdate::Filled { order_id: id, fill_price: 100.0, fill_size: 2.0, remaining_size: 0.0, fee: 0.0 }
+        );
+        assert!(emulator.order(id).is_none());
+    }
+
+    #[test]
+    fn a_cancel_notifies_the_owning_strategy() {
+        let mut emulator = MarketEmulator::new();
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 1.0);
+        let updates = Rc::new(RefCell::new(Vec::new()));
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![Box::new(NotifiedStrategy { updates: updates.clone() })];
+
+        apply_cancel(&mut strategies, &mut emulator, 0, id).unwrap();
+
+        assert_eq!(updates.borrow()[0], OrderUpdate::Cancelled { order_id: id });
+        assert!(emulator.order(id).is_none());
+    }
+
+    #[test]
+    fn a_queued_order_does_not_fill_until_enough_volume_trades_through() {
+        let mut emulator = MarketEmulator::with_settings(EmulatorSettings { use_queue_model: true, ..Default::default() });
+        let id = emulator.place_limit_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 1.0, 5.0);
+        assert!(!emulator.is_fillable(id));
+
+        emulator.record_trade_at(100.0, 3.0);
+        assert!(!emulator.is_fillable(id));
+
+        emulator.record_trade_at(100.0, 2.0);
+        assert!(emulator.is_fillable(id));
+    }
+
+    #[test]
+    fn the_queue_model_is_ignored_when_the_setting_is_off() {
+        let mut emulator = MarketEmulator::new();
+        let id = emulator.place_limit_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 1.0, 5.0);
+        assert!(emulator.is_fillable(id));
+    }
+
+    #[test]
+    fn trades_at_other_prices_do_not_advance_this_orders_queue() {
+        let mut emulator = MarketEmulator::with_settings(EmulatorSettings { use_queue_model: true, ..Default::default() });
+        let id = emulator.place_limit_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 1.0, 5.0);
+
+        emulator.record_trade_at(101.0, 10.0);
+        assert!(!emulator.is_fillable(id));
+    }
+
+    #[test]
+    fn replacing_a_specific_order_among_several_affects_only_that_one() {
+        let mut emulator = MarketEmulator::new();
+        let order_ids = Rc::new(RefCell::new(Vec::new()));
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![Box::new(ScriptedStrategy {
+            queued: vec![
+                StrategyAction::PlaceBuy {
+                    symbol: "BTCUSDT".into(),
+                    price: 100.0,
+                    size: 1.0,
+                },
+                StrategyAction::PlaceBuy {
+                    symbol: "BTCUSDT".into(),
+                    price: 101.0,
+                    size: 1.0,
+                },
+            ],
+            order_ids: order_ids.clone(),
+        })];
+        recalculate_strategies(&mut strategies, &mut emulator);
+
+        let ids = order_ids.borrow().clone();
+        assert_eq!(ids.len(), 2);
+        let (first_id, second_id) = (ids[0], ids[1]);
+
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![Box::new(ScriptedStrategy {
+            queued: vec![StrategyAction::ReplaceBuy {
+                order_id: second_id,
+                new_price: 150.0,
+            }],
+            order_ids: Rc::new(RefCell::new(Vec::new())),
+        })];
+        recalculate_strategies(&mut strategies, &mut emulator);
+
+        assert_eq!(emulator.order(first_id).unwrap().price, 100.0);
+        assert_eq!(emulator.order(second_id).unwrap().price, 150.0);
+    }
+
+    #[test]
+    fn cross_strategy_cancel_is_rejected() {
+        let mut emulator = MarketEmulator::new();
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 1.0);
+        assert!(emulator.cancel_order(1, id).is_err());
+        assert!(emulator.order(id).is_some());
+        assert!(emulator.cancel_order(0, id).is_ok());
+        assert!(emulator.order(id).is_none());
+    }
+
+    #[test]
+    fn apply_pnl_accumulates_into_the_account_balance() {
+        let mut emulator = MarketEmulator::with_balance(1_000.0);
+        emulator.apply_pnl(50.0);
+        emulator.apply_pnl(-20.0);
+        assert_eq!(emulator.balance(), 1_030.0);
+    }
+
+    #[test]
+    fn a_passive_fill_is_charged_the_maker_fee() {
+        let settings = EmulatorSettings { maker_fee: 0.0001, taker_fee: 0.0005, ..Default::default() };
+        let mut emulator = MarketEmulator::with_settings(settings);
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 2.0);
+
+        let update = emulator.fill_order(id, 100.0, 2.0, true).unwrap();
+
+        assert_eq!(update, OrderUpdate::Filled { order_id: id, fill_price: 100.0, fill_size: 2.0, remaining_size: 0.0, fee: 0.02 });
+        assert_eq!(emulator.total_fees(), 0.02);
+    }
+
+    #[test]
+    fn an_aggressive_fill_is_charged_the_taker_fee() {
+        let settings = EmulatorSettings { maker_fee: 0.0001, taker_fee: 0.0005, ..Default::default() };
+        let mut emulator = MarketEmulator::with_settings(settings);
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 2.0);
+
+        let update = emulator.fill_order(id, 100.0, 2.0, false).unwrap();
+
+        assert_eq!(update, OrderUpdate::Filled { order_id: id, fill_price: 100.0, fill_size: 2.0, remaining_size: 0.0, fee: 0.1 });
+        assert_eq!(emulator.total_fees(), 0.1);
+    }
+
+    #[test]
+    fn a_negative_maker_fee_is_a_rebate_that_reduces_total_fees() {
+        let settings = EmulatorSettings { maker_fee: -0.0002, taker_fee: 0.0005, ..Default::default() };
+        let mut emulator = MarketEmulator::with_settings(settings);
+        let id = emulator.place_order(0, "BTCUSDT".into(), Side::Buy, 100.0, 2.0);
+
+        emulator.fill_order(id, 100.0, 2.0, true).unwrap();
+
+        assert_eq!(emulator.total_fees(), -0.04);
+    }
+}
@@ -0,0 +1,728 @@
+//! Drives a [`TradeStream`] tick by tick through a strategy [`Adapter`],
+//! recording whatever trades it closes into [`BacktestMetrics`].
+
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::backtest::metrics::{BacktestMetrics, BacktestResult, MonteCarloSummary, Side, Trade};
+use crate::backtest::replay::TradeStream;
+use crate::models::TradeTick;
+
+/// A position still open when [`Adapter::open_position`] is asked, so
+/// [`BacktestEngine::run`] can apply any funding payments scheduled via
+/// [`BacktestEngine::add_funding_rates`] against it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenPosition {
+    pub symbol: String,
+    pub side: Side,
+    pub size: f64,
+    pub price: f64,
+}
+
+/// Translates raw ticks into closed trades for a single strategy. Most
+/// ticks won't close anything, so `on_tick` returns `None` far more often
+/// than `Some`.
+pub trait Adapter {
+    fn on_tick(&mut self, tick: &TradeTick) -> Option<crate::backtest::metrics::Trade>;
+
+    /// Clears any per-run state (open positions, indicator warmup, etc.)
+    /// so the adapter can be reused across independent runs, as
+    /// [`BacktestEngine::run_walk_forward`] does between windows. The
+    /// default no-op is correct for a stateless adapter.
+    fn reset(&mut self) {}
+
+    /// The adapter's currently open position, if any. [`BacktestEngine::run`]
+    /// checks this at every funding timestamp set via
+    /// [`BacktestEngine::add_funding_rates`] to decide whether a funding
+    /// payment applies. The default of `None` is correct for an adapter
+    /// that never holds a position across ticks.
+    fn open_position(&self) -> Option<OpenPosition> {
+        None
+    }
+}
+
+/// Converts a tick's nanosecond timestamp into a `DateTime<Utc>`, as used on
+/// the [`crate::backtest::metrics::Trade`] records an [`Adapter`] produces.
+pub fn tick_time(ts_ns: i64) -> DateTime<Utc> {
+    DateTime::<Utc>::from_timestamp(ts_ns / 1_000_000_000, (ts_ns.rem_euclid(1_000_000_000)) as u32)
+        .unwrap_or_default()
+}
+
+/// A snapshot of how far a [`BacktestEngine::run`] has gotten, sent on the
+/// channel set via [`BacktestEngine::with_progress_channel`] so a caller
+/// (e.g. forwarding to a UI over a websocket) isn't limited to a single
+/// start/complete message.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BacktestProgress {
+    pub ticks_processed: usize,
+    pub total_ticks: usize,
+    pub current_pnl: f64,
+    pub trades: usize,
+}
+
+/// Runs one `Adapter` over a `TradeStream` to completion, collecting every
+/// trade it closes along the way.
+pub struct BacktestEngine<A: Adapter> {
+    adapter: A,
+    seed: u64,
+    progress: Option<(Sender<BacktestProgress>, usize)>,
+    funding_schedule: HashMap<String, Vec<(DateTime<Utc>, f64)>>,
+    funding_cursor: HashMap<String, usize>,
+}
+
+impl<A: Adapter> BacktestEngine<A> {
+    pub fn new(adapter: A) -> Self {
+        BacktestEngine {
+            adapter,
+            seed: 0,
+            progress: None,
+            funding_schedule: HashMap::new(),
+            funding_cursor: HashMap::new(),
+        }
+    }
+
+    /// Schedules funding payments for `symbol` at each `(timestamp, rate)`
+    /// pair, applied during [`BacktestEngine::run`] against whatever
+    /// position [`Adapter::open_position`] reports open once a tick's time
+    /// reaches that timestamp. `rate` is a fraction of the position's
+    /// notional; a long position pays a positive rate and receives a
+    /// negative one (a short position the reverse). `schedule` is sorted by
+    /// timestamp before use, so callers don't have to pre-sort it.
+    pub fn add_funding_rates(mut self, symbol: impl Into<String>, mut schedule: Vec<(DateTime<Utc>, f64)>) -> Self {
+        schedule.sort_by_key(|(ts, _)| *ts);
+        self.funding_schedule.insert(symbol.into(), schedule);
+        self
+    }
+
+    /// Applies every funding event up to and including `now` against
+    /// whatever position the adapter currently has open, recording each
+    /// payment into `metrics`.
+    fn apply_due_funding(&mut self, now: DateTime<Utc>, metrics: &mut BacktestMetrics) {
+        let Some(position) = self.adapter.open_position() else { return };
+        let Some(schedule) = self.funding_schedule.get(&position.symbol) else { return };
+        let cursor = self.funding_cursor.entry(position.symbol.clone()).or_insert(0);
+        while *cursor < schedule.len() && schedule[*cursor].0 <= now {
+            let rate = schedule[*cursor].1;
+            let notional = position.size * position.price;
+            let payment = match position.side {
+                Side::Buy => notional * rate,
+                Side::Sell => -notional * rate,
+            };
+            metrics.record_funding(payment);
+            *cursor += 1;
+        }
+    }
+
+    /// Sends a [`BacktestProgress`] snapshot on `tx` every `every_n_ticks`
+    /// ticks during [`BacktestEngine::run`], so a long-running backtest can
+    /// report progress without the caller reimplementing tick counting
+    /// outside the engine. A send error (no receiver left) is ignored —
+    /// the run itself isn't the progress channel's problem.
+    pub fn with_progress_channel(mut self, tx: Sender<BacktestProgress>, every_n_ticks: usize) -> Self {
+        self.progress = Some((tx, every_n_ticks.max(1)));
+        self
+    }
+
+    /// Records the seed this run should be considered to have used, so it
+    /// can be carried into a saved [`crate::backtest::metrics::RunMetadata::seed`].
+    /// This engine's `run` methods have no internal randomness of their
+    /// own to seed — every [`Adapter`] is a pure function of the ticks it's
+    /// fed, so two engines built with the same seed over the same stream
+    /// already produce bit-identical [`BacktestResult`]s without this.
+    /// This only matters once a caller's own `Adapter` consumes randomness
+    /// and wants the seed that drove it on record.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// The seed set via [`BacktestEngine::with_seed`], or `0` if none was
+    /// set.
+    pub fn seed_used(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn run(&mut self, mut stream: TradeStream) -> BacktestMetrics {
+        let mut metrics = BacktestMetrics::new();
+        let total_ticks = stream.remaining();
+        let mut ticks_processed = 0_usize;
+        while let Some(tick) = stream.next_tick() {
+            ticks_processed += 1;
+            self.apply_due_funding(tick_time(tick.ts_ns), &mut metrics);
+            if let Some(trade) = self.adapter.on_tick(tick) {
+                metrics.record_trade(trade);
+            }
+            if let Some((tx, every_n_ticks)) = &self.progress {
+                if ticks_processed.is_multiple_of(*every_n_ticks) {
+                    let _ = tx.send(BacktestProgress {
+                        ticks_processed,
+                        total_ticks,
+                        current_pnl: metrics.result().total_pnl,
+                        trades: metrics.trades().len(),
+                    });
+                }
+            }
+        }
+        metrics
+    }
+
+    /// Like [`BacktestEngine::run`], but aborts with [`BacktestTimeout`]
+    /// (carrying whatever trades were collected so far) if `max_wall_time`
+    /// elapses before the stream is exhausted. Checked once per tick, so a
+    /// pathological strategy or an oversized dataset can't run unbounded.
+    pub fn run_with_timeout(
+        &mut self,
+        mut stream: TradeStream,
+        max_wall_time: Duration,
+    ) -> Result<BacktestMetrics, BacktestTimeout> {
+        let mut metrics = BacktestMetrics::new();
+        let start = Instant::now();
+        while let Some(tick) = stream.next_tick() {
+            if start.elapsed() > max_wall_time {
+                return Err(BacktestTimeout { partial: metrics });
+            }
+            if let Some(trade) = self.adapter.on_tick(tick) {
+                metrics.record_trade(trade);
+            }
+        }
+        Ok(metrics)
+    }
+
+    /// Slices `stream` into rolling `window`-wide chunks advancing by
+    /// `step`, running the adapter fresh (via [`Adapter::reset`]) over each
+    /// window and collecting its result. Every window is run the same way;
+    /// there is no separate in-sample portion. This checks a
+    /// strategy's performance holds up window over window instead of just
+    /// over the whole dataset at once, where it could be overfit to one
+    /// lucky stretch.
+    pub fn run_walk_forward(
+        &mut self,
+        stream: &TradeStream,
+        window: Duration,
+        step: Duration,
+    ) -> Vec<crate::backtest::metrics::BacktestResult> {
+        let Some((start, end)) = stream.time_bounds() else { return Vec::new() };
+        let window_ns = window.as_nanos() as i64;
+        let step_ns = (step.as_nanos() as i64).max(1);
+
+        let mut results = Vec::new();
+        let mut window_start = start;
+        while window_start <= end {
+            let window_slice = stream.slice_by_time(window_start, window_start + window_ns);
+            self.adapter.reset();
+            results.push(self.run(window_slice).result());
+            window_start += step_ns;
+        }
+        results
+    }
+
+    /// Like [`BacktestEngine::run`], but every trade the adapter closes is
+    /// held in a [`DelayedEventQueue`] and only recorded once simulated
+    /// time advances `latency` past the tick that closed it, instead of
+    /// landing immediately.
+    pub fn run_with_latency(&mut self, mut stream: TradeStream, latency: Duration) -> BacktestMetrics {
+        let mut metrics = BacktestMetrics::new();
+        let mut queue = DelayedEventQueue::new();
+        let latency_ns = latency.as_nanos() as i64;
+
+        while let Some(tick) = stream.next_tick() {
+            queue.process_delayed_events(tick.ts_ns, &mut metrics);
+            if let Some(trade) = self.adapter.on_tick(tick) {
+                queue.schedule(trade, tick.ts_ns + latency_ns);
+            }
+        }
+        // Flush whatever is still pending once the stream ends, so a fill
+        // whose `execute_at_ns` lands after the last tick isn't silently
+        // dropped instead of recorded.
+        queue.process_delayed_events(i64::MAX, &mut metrics);
+        metrics
+    }
+
+    /// Resamples a completed run's `trades` into `resamples.len()`
+    /// alternate trade sequences, computing a [`BacktestResult`] for each.
+    /// This crate has no RNG dependency, so `resamples` (each a list of
+    /// indices into `trades`, with or without repetition) is caller-built —
+    /// callers that want bootstrap resampling can generate the indices
+    /// however they like and pass them in, the same injected-randomness
+    /// pattern [`crate::backtest::replay::PacedReplay`] uses for its sleep.
+    pub fn run_monte_carlo(trades: &[Trade], resamples: &[Vec<usize>]) -> Vec<BacktestResult> {
+        resamples
+            .iter()
+            .map(|indices| {
+                let picked: Vec<&Trade> = indices.iter().map(|&i| &trades[i]).collect();
+                BacktestResult::from_trades(&picked)
+            })
+            .collect()
+    }
+
+    /// Like [`BacktestEngine::run_monte_carlo`], but also reduces the
+    /// results into a [`MonteCarloSummary`].
+    pub fn run_monte_carlo_summary(
+        trades: &[Trade],
+        resamples: &[Vec<usize>],
+    ) -> (Vec<BacktestResult>, MonteCarloSummary) {
+        let results = Self::run_monte_carlo(trades, resamples);
+        let summary = crate::backtest::metrics::summarize_monte_carlo(&results);
+        (results, summary)
+    }
+}
+
+/// Returned by [`BacktestEngine::run_with_timeout`] when `max_wall_time`
+/// elapses before the run finished.
+#[derive(Debug)]
+pub struct BacktestTimeout {
+    pub partial: BacktestMetrics,
+}
+
+/// One fill waiting to be applied once simulated time reaches
+/// `execute_at_ns`, as modeled by [`DelayedEventQueue`].
+#[derive(Debug, Clone)]
+pub enum DelayedEvent {
+    OrderExecution { execute_at_ns: i64, trade: Trade },
+}
+
+/// Holds fills that shouldn't land immediately, e.g. to model order-routing
+/// latency between a strategy's decision and the exchange actually filling
+/// it.
+#[derive(Debug, Clone, Default)]
+pub struct DelayedEventQueue {
+    events: Vec<DelayedEvent>,
+}
+
+impl DelayedEventQueue {
+    pub fn new() -> Self {
+        DelayedEventQueue { events: Vec::new() }
+    }
+
+    /// Schedules `trade` to be applied once simulated time reaches
+    /// `execute_at_ns`.
+    pub fn schedule(&mut self, trade: Trade, execute_at_ns: i64) {
+        self.events.push(DelayedEvent::OrderExecution { execute_at_ns, trade });
+    }
+
+    pub fn pending(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Records every event whose `execute_at_ns` has been reached into
+    /// `metrics`, removing it from the queue. Events scheduled for later
+    /// than `now_ns` are left pending.
+    pub fn process_delayed_events(&mut self, now_ns: i64, metrics: &mut BacktestMetrics) {
+        let mut i = 0;
+        while i < self.events.len() {
+            let DelayedEvent::OrderExecution { execute_at_ns, .. } = &self.events[i];
+            if *execute_at_ns <= now_ns {
+                let DelayedEvent::OrderExecution { trade, .. } = self.events.remove(i);
+                metrics.record_trade(trade);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::metrics::{Side, Trade};
+    use crate::models::Side as TickSide;
+
+    /// Closes a long trade every time it sees a Sell tick after a prior Buy
+    /// tick, using the fixed entry/exit pair as the trade's prices.
+    struct FlipFlopAdapter {
+        entry_price: Option<f64>,
+    }
+
+    impl Adapter for FlipFlopAdapter {
+        fn on_tick(&mut self, tick: &TradeTick) -> Option<Trade> {
+            match tick.side {
+                TickSide::Buy => {
+                    self.entry_price = Some(tick.price);
+                    None
+                }
+                TickSide::Sell => {
+                    let entry_price = self.entry_price.take()?;
+                    let now = tick_time(tick.ts_ns);
+                    Some(Trade {
+                        symbol: "BTCUSDT".into(),
+                        side: Side::Buy,
+                        entry_price,
+                        exit_price: tick.price,
+                        size: tick.size,
+                        pnl: (tick.price - entry_price) * tick.size,
+                        fees: 0.0,
+                        opened_at: now,
+                        closed_at: now,
+                        strategy_id: "flip_flop".into(),
+                    })
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn runs_a_stream_through_an_adapter_and_collects_its_trades() {
+        let ticks = vec![
+            TradeTick {
+                ts_ns: 0,
+                price: 100.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 1_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 2_000_000_000,
+                price: 105.0,
+                size: 2.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 3_000_000_000,
+                price: 108.0,
+                size: 2.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+        ];
+
+        let mut engine = BacktestEngine::new(FlipFlopAdapter { entry_price: None });
+        let metrics = engine.run(TradeStream::new(ticks));
+
+        let result = metrics.result();
+        assert_eq!(result.total_trades, 2);
+        assert_eq!(result.total_pnl, 16.0);
+    }
+
+    fn flip_flop_ticks() -> Vec<TradeTick> {
+        vec![
+            TradeTick { ts_ns: 0, price: 100.0, size: 1.0, side: TickSide::Buy, best_bid: None, best_ask: None },
+            TradeTick {
+                ts_ns: 1_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn two_engines_built_with_the_same_seed_produce_bit_identical_results() {
+        let mut first = BacktestEngine::new(FlipFlopAdapter { entry_price: None }).with_seed(42);
+        let mut second = BacktestEngine::new(FlipFlopAdapter { entry_price: None }).with_seed(42);
+
+        let first_result = first.run(TradeStream::new(flip_flop_ticks())).result();
+        let second_result = second.run(TradeStream::new(flip_flop_ticks())).result();
+
+        assert_eq!(first.seed_used(), second.seed_used());
+        assert_eq!(first_result.total_pnl, second_result.total_pnl);
+        assert_eq!(first_result.total_trades, second_result.total_trades);
+    }
+
+    #[test]
+    fn with_progress_channel_reports_monotonically_increasing_tick_counts() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut engine = BacktestEngine::new(FlipFlopAdapter { entry_price: None }).with_progress_channel(tx, 1);
+
+        engine.run(TradeStream::new(flip_flop_ticks()));
+
+        let progress: Vec<BacktestProgress> = rx.try_iter().collect();
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress[0].total_ticks, 2);
+        for window in progress.windows(2) {
+            assert!(window[1].ticks_processed > window[0].ticks_processed);
+        }
+        assert_eq!(progress.last().unwrap().ticks_processed, 2);
+    }
+
+    /// An adapter that sleeps a little on every tick, so a wall-time budget
+    /// is guaranteed to be exceeded well before a large dataset is
+    /// exhausted.
+    struct SlowAdapter;
+
+    impl Adapter for SlowAdapter {
+        fn on_tick(&mut self, _tick: &TradeTick) -> Option<Trade> {
+            std::thread::sleep(Duration::from_millis(2));
+            None
+        }
+    }
+
+    /// Counts how many times it's been reset, so a test can confirm
+    /// `run_walk_forward` resets state between windows rather than
+    /// carrying it over.
+    struct CountingAdapter {
+        resets: std::rc::Rc<std::cell::RefCell<usize>>,
+    }
+
+    impl Adapter for CountingAdapter {
+        fn on_tick(&mut self, _tick: &TradeTick) -> Option<Trade> {
+            None
+        }
+
+        fn reset(&mut self) {
+            *self.resets.borrow_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn run_walk_forward_resets_the_adapter_once_per_window() {
+        let ticks: Vec<TradeTick> = (0..10)
+            .map(|i| TradeTick {
+                ts_ns: i * 1_000_000_000,
+                price: 100.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            })
+            .collect();
+        let stream = TradeStream::new(ticks);
+        let resets = std::rc::Rc::new(std::cell::RefCell::new(0));
+
+        let mut engine = BacktestEngine::new(CountingAdapter { resets: resets.clone() });
+        let results = engine.run_walk_forward(&stream, Duration::from_secs(3), Duration::from_secs(3));
+
+        assert_eq!(results.len(), *resets.borrow());
+        assert!(results.len() >= 3);
+    }
+
+    #[test]
+    fn a_fill_scheduled_with_latency_applies_only_after_simulated_time_passes_execute_at() {
+        let mut queue = DelayedEventQueue::new();
+        let mut metrics = BacktestMetrics::new();
+        let trade = Trade {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            entry_price: 100.0,
+            exit_price: 101.0,
+            size: 1.0,
+            pnl: 1.0,
+            fees: 0.0,
+            opened_at: tick_time(0),
+            closed_at: tick_time(0),
+            strategy_id: "latency_test".into(),
+        };
+        queue.schedule(trade, 1_000_000_000);
+
+        queue.process_delayed_events(500_000_000, &mut metrics);
+        assert_eq!(queue.pending(), 1);
+        assert_eq!(metrics.trades().len(), 0);
+
+        queue.process_delayed_events(1_000_000_000, &mut metrics);
+        assert_eq!(queue.pending(), 0);
+        assert_eq!(metrics.trades().len(), 1);
+    }
+
+    #[test]
+    fn run_with_latency_delays_every_fill_by_the_configured_amount() {
+        let ticks = vec![
+            TradeTick { ts_ns: 0, price: 100.0, size: 1.0, side: TickSide::Buy, best_bid: None, best_ask: None },
+            TradeTick {
+                ts_ns: 1_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+            // A third tick just to push simulated time past the 1s latency
+            // so the fill closed by the Sell tick gets applied within the
+            // loop, before the stream ends.
+            TradeTick {
+                ts_ns: 2_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+        ];
+
+        let mut engine = BacktestEngine::new(FlipFlopAdapter { entry_price: None });
+        let metrics = engine.run_with_latency(TradeStream::new(ticks), Duration::from_secs(1));
+
+        assert_eq!(metrics.trades().len(), 1);
+        assert_eq!(metrics.result().total_pnl, 10.0);
+    }
+
+    #[test]
+    fn run_with_latency_flushes_a_fill_whose_execute_time_is_past_the_last_tick() {
+        let ticks = vec![
+            TradeTick { ts_ns: 0, price: 100.0, size: 1.0, side: TickSide::Buy, best_bid: None, best_ask: None },
+            TradeTick {
+                ts_ns: 1_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+        ];
+
+        // Latency extends the Sell tick's fill to execute_at_ns =
+        // 1_000_000_000 + 5s, well past the stream's last tick at 1s.
+        let mut engine = BacktestEngine::new(FlipFlopAdapter { entry_price: None });
+        let metrics = engine.run_with_latency(TradeStream::new(ticks), Duration::from_secs(5));
+
+        assert_eq!(metrics.trades().len(), 1);
+        assert_eq!(metrics.result().total_pnl, 10.0);
+    }
+
+    /// Holds a long position from its first Buy tick until a Sell tick
+    /// closes it, exposing the held position via `open_position` so funding
+    /// can be charged against it in between.
+    struct HeldPositionAdapter {
+        entry_price: Option<f64>,
+        size: f64,
+    }
+
+    impl Adapter for HeldPositionAdapter {
+        fn on_tick(&mut self, tick: &TradeTick) -> Option<Trade> {
+            match tick.side {
+                TickSide::Buy if self.entry_price.is_none() => {
+                    self.entry_price = Some(tick.price);
+                    None
+                }
+                TickSide::Sell => {
+                    let entry_price = self.entry_price.take()?;
+                    let now = tick_time(tick.ts_ns);
+                    Some(Trade {
+                        symbol: "BTCUSDT".into(),
+                        side: Side::Buy,
+                        entry_price,
+                        exit_price: tick.price,
+                        size: self.size,
+                        pnl: (tick.price - entry_price) * self.size,
+                        fees: 0.0,
+                        opened_at: now,
+                        closed_at: now,
+                        strategy_id: "held_position".into(),
+                    })
+                }
+                _ => None,
+            }
+        }
+
+        fn open_position(&self) -> Option<OpenPosition> {
+            self.entry_price.map(|price| OpenPosition {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                size: self.size,
+                price,
+            })
+        }
+    }
+
+    #[test]
+    fn funding_is_charged_against_a_position_held_across_two_funding_events() {
+        let ticks = vec![
+            TradeTick { ts_ns: 0, price: 100.0, size: 1.0, side: TickSide::Buy, best_bid: None, best_ask: None },
+            TradeTick {
+                ts_ns: 1_000_000_000,
+                price: 100.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 2_000_000_000,
+                price: 100.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 3_000_000_000,
+                price: 110.0,
+                size: 1.0,
+                side: TickSide::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+        ];
+        let funding_schedule = vec![
+            (tick_time(1_000_000_000), 0.01),
+            (tick_time(2_000_000_000), 0.01),
+        ];
+
+        let mut engine =
+            BacktestEngine::new(HeldPositionAdapter { entry_price: None, size: 1.0 }).add_funding_rates("BTCUSDT", funding_schedule);
+        let metrics = engine.run(TradeStream::new(ticks));
+
+        let result = metrics.result();
+        // Long position of notional 100 pays 0.01 * 100 = 1.0 at each of the
+        // two funding events, for 2.0 total.
+        assert_eq!(result.total_funding_paid, 2.0);
+        assert_eq!(result.total_pnl, 10.0 - 2.0);
+    }
+
+    #[test]
+    fn run_monte_carlo_summary_computes_a_result_and_summary_per_resample() {
+        let trades: Vec<Trade> = [10.0, -5.0, 20.0].iter().map(|&pnl| trade_with_pnl(pnl)).collect();
+        // Two fixed "resamples": every trade once, and only the winners.
+        let resamples = vec![vec![0, 1, 2], vec![0, 2]];
+
+        let (results, summary) = BacktestEngine::<FlipFlopAdapter>::run_monte_carlo_summary(&trades, &resamples);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].total_pnl, 25.0);
+        assert_eq!(results[1].total_pnl, 30.0);
+        assert!((summary.mean_pnl - 27.5).abs() < 1e-9);
+        assert_eq!(summary.probability_of_loss, 0.0);
+    }
+
+    fn trade_with_pnl(pnl: f64) -> Trade {
+        let now = tick_time(0);
+        Trade {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            entry_price: 100.0,
+            exit_price: 100.0 + pnl,
+            size: 1.0,
+            pnl,
+            fees: 0.0,
+            opened_at: now,
+            closed_at: now,
+            strategy_id: "monte_carlo_test".into(),
+        }
+    }
+
+    #[test]
+    fn run_with_timeout_aborts_a_pathologically_large_dataset() {
+        let ticks: Vec<TradeTick> = (0..1_000)
+            .map(|i| TradeTick {
+                ts_ns: i,
+                price: 100.0,
+                size: 1.0,
+                side: TickSide::Buy,
+                best_bid: None,
+                best_ask: None,
+            })
+            .collect();
+
+        let mut engine = BacktestEngine::new(SlowAdapter);
+        let result = engine.run_with_timeout(TradeStream::new(ticks), Duration::from_millis(5));
+
+        let timeout = result.expect_err("a 5ms budget against 1000 ticks at 2ms each must time out");
+        assert!(timeout.partial.trades().len() < 1_000);
+    }
+}
@@ -0,0 +1,64 @@
+//! Models that some venues' feeds drop more trades than others, so a
+//! cross-venue backtest doesn't treat every exchange as equally reliable.
+//!
+//! Like [`crate::backtest::replay::PacedReplay`]'s injected `sleep`
+//! closure, the "randomness" here is a caller-supplied `roll` rather than
+//! an internal RNG, so callers (and tests) control it directly instead of
+//! needing a seeded PRNG dependency.
+
+use std::collections::HashMap;
+
+/// Per-exchange probability that a trade tick from that feed is missed,
+/// falling back to `default_probability` for any exchange without an
+/// explicit override.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedReliability {
+    default_probability: f64,
+    overrides: HashMap<String, f64>,
+}
+
+impl FeedReliability {
+    pub fn new(default_probability: f64) -> Self {
+        FeedReliability { default_probability, overrides: HashMap::new() }
+    }
+
+    /// Sets `exchange`'s miss probability, overriding the default for it.
+    pub fn with_probability(mut self, exchange: impl Into<String>, probability: f64) -> Self {
+        self.overrides.insert(exchange.into(), probability);
+        self
+    }
+
+    /// The configured miss probability for `exchange`.
+    pub fn probability_for(&self, exchange: &str) -> f64 {
+        self.overrides.get(exchange).copied().unwrap_or(self.default_probability)
+    }
+
+    /// Whether a tick from `exchange` should be dropped, given `roll`, a
+    /// caller-supplied value in `[0.0, 1.0)`.
+    pub fn should_miss(&self, exchange: &str, roll: f64) -> bool {
+        roll < self.probability_for(exchange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_exchange_without_an_override_uses_the_default_probability() {
+        let reliability = FeedReliability::new(0.1);
+        assert_eq!(reliability.probability_for("gate"), 0.1);
+    }
+
+    #[test]
+    fn a_zero_probability_feed_never_misses_and_a_certain_one_always_does() {
+        let reliability = FeedReliability::new(0.1)
+            .with_probability("venue_a", 0.0)
+            .with_probability("venue_b", 1.0);
+
+        for roll in [0.0, 0.25, 0.5, 0.75, 0.999] {
+            assert!(!reliability.should_miss("venue_a", roll), "venue_a missed at roll {roll}");
+            assert!(reliability.should_miss("venue_b", roll), "venue_b kept a trade at roll {roll}");
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Tiered leverage limits: exchanges cap how much leverage a position can
+//! use as its notional grows, rather than allowing a flat multiplier at any
+//! size. Sibling to [`crate::backtest::commission::CommissionModel`] as a
+//! small, table-driven piece of the emulator's margin accounting.
+
+use crate::models::Side;
+
+/// One notional band's leverage cap and maintenance margin rate. `max_notional`
+/// is this tier's upper bound; a position with a larger notional falls into
+/// the next (lower-leverage) tier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeverageTier {
+    pub max_notional: f64,
+    pub max_leverage: f64,
+    pub maintenance_margin_rate: f64,
+}
+
+/// A sorted table of [`LeverageTier`]s, ascending by `max_notional`.
+pub struct LeverageTable {
+    tiers: Vec<LeverageTier>,
+}
+
+impl LeverageTable {
+    /// Sorts `tiers` by `max_notional` ascending; the last tier covers
+    /// every notional above its own band.
+    pub fn new(mut tiers: Vec<LeverageTier>) -> Self {
+        tiers.sort_by(|a, b| a.max_notional.partial_cmp(&b.max_notional).unwrap());
+        LeverageTable { tiers }
+    }
+
+    /// The tier `notional` falls into: the first tier whose `max_notional`
+    /// it doesn't exceed, or the last (lowest-leverage) tier if it exceeds
+    /// them all.
+    pub fn tier_for(&self, notional: f64) -> &LeverageTier {
+        self.tiers
+            .iter()
+            .find(|tier| notional <= tier.max_notional)
+            .unwrap_or_else(|| self.tiers.last().expect("LeverageTable must have at least one tier"))
+    }
+
+    pub fn max_leverage_for(&self, notional: f64) -> f64 {
+        self.tier_for(notional).max_leverage
+    }
+
+    /// The price at which a position of `notional` opened at `entry_price`
+    /// with `leverage` gets liquidated, or an error if `leverage` exceeds
+    /// the cap for that notional's tier.
+    pub fn liquidation_price(&self, entry_price: f64, notional: f64, leverage: f64, side: Side) -> Result<f64, String> {
+        let tier = self.tier_for(notional);
+        if leverage > tier.max_leverage {
+            return Err(format!(
+                "leverage {leverage}x exceeds the {}x cap for a notional of {notional}",
+                tier.max_leverage
+            ));
+        }
+
+        let margin_fraction = 1.0 / leverage;
+        Ok(match side {
+            Side::Buy => entry_price * (1.0 - margin_fraction + tier.maintenance_margin_rate),
+            Side::Sell => entry_price * (1.0 + margin_fraction - tier.maintenance_margin_rate),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> LeverageTable {
+        LeverageTable::new(vec![
+            LeverageTier { max_notional: 10_000.0, max_leverage: 125.0, maintenance_margin_rate: 0.004 },
+            LeverageTier { max_notional: 100_000.0, max_leverage: 50.0, maintenance_margin_rate: 0.005 },
+            LeverageTier { max_notional: f64::MAX, max_leverage: 10.0, maintenance_margin_rate: 0.01 },
+        ])
+    }
+
+    #[test]
+    fn a_large_position_is_capped_to_a_lower_max_leverage() {
+        let table = table();
+        assert_eq!(table.max_leverage_for(5_000.0), 125.0);
+        assert_eq!(table.max_leverage_for(50_000.0), 50.0);
+        assert_eq!(table.max_leverage_for(500_000.0), 10.0);
+    }
+
+    #[test]
+    fn leverage_above_the_tiers_cap_is_rejected() {
+        let table = table();
+        assert!(table.liquidation_price(100.0, 50_000.0, 100.0, Side::Buy).is_err());
+    }
+
+    #[test]
+    fn liquidation_price_reflects_the_tiers_margin_rate() {
+        let table = table();
+        // 50_000 notional falls in the second tier: 50x cap, 0.5% maintenance margin.
+        let liq = table.liquidation_price(100.0, 50_000.0, 50.0, Side::Buy).unwrap();
+        // entry * (1 - 1/50 + 0.005) = 100 * (1 - 0.02 + 0.005) = 98.5
+        assert!((liq - 98.5).abs() < 1e-9);
+
+        let liq_short = table.liquidation_price(100.0, 50_000.0, 50.0, Side::Sell).unwrap();
+        // entry * (1 + 1/50 - 0.005) = 100 * (1 + 0.02 - 0.005) = 101.5
+        assert!((liq_short - 101.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,766 @@
+//! Aggregates trades produced during a backtest into summary statistics.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+/// A single closed trade produced by a strategy during a backtest.
+#[derive(Debug, Clone)]
+pub struct Trade {
+    pub symbol: String,
+    pub side: Side,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub size: f64,
+    pub pnl: f64,
+    pub fees: f64,
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: DateTime<Utc>,
+    /// Id of the strategy/adapter that produced this trade, so multi-strategy
+    /// runs can report per-strategy results.
+    pub strategy_id: String,
+}
+
+/// Everything needed to reproduce a backtest run later, so a saved
+/// [`BacktestResult`] is self-describing instead of relying on whoever ran
+/// it to remember the exact settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    pub strategy_name: String,
+    pub strategy_params: String,
+    pub symbol: String,
+    pub data_start: DateTime<Utc>,
+    pub data_end: DateTime<Utc>,
+    /// The deterministic seed the run used, if any; `None` for a run with
+    /// no randomized component.
+    pub seed: Option<u64>,
+}
+
+/// Aggregate statistics computed from a set of [`Trade`]s.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestResult {
+    pub total_trades: usize,
+    pub winning_trades: usize,
+    pub total_pnl: f64,
+    pub gross_profit: f64,
+    pub gross_loss: f64,
+    pub total_fees: f64,
+    /// Net funding payments applied via [`BacktestMetrics::record_funding`]
+    /// while a perpetual position was held through a funding timestamp.
+    /// Positive when the run paid out more funding than it received.
+    pub total_funding_paid: f64,
+    /// Max peak-to-trough drawdown of the cumulative P&L across the
+    /// trades, the same measure as [`crate::analytics::performance::RollingPerformance::drawdown`].
+    pub max_drawdown: f64,
+    /// RMS of the peak-to-trough drawdown at every point in the run, so a
+    /// strategy that spends a long time underwater is penalized even if it
+    /// never sets a new [`BacktestResult::max_drawdown`] record.
+    pub ulcer_index: f64,
+    pub run_metadata: Option<RunMetadata>,
+}
+
+impl BacktestResult {
+    pub fn win_rate(&self) -> f64 {
+        if self.total_trades == 0 {
+            0.0
+        } else {
+            self.winning_trades as f64 / self.total_trades as f64
+        }
+    }
+
+    /// Total fees as a fraction of gross profit, so fee drag is visible
+    /// independent of the run's absolute size. `0.0` if there was no gross
+    /// profit to compare against, rather than dividing by zero.
+    pub fn fee_ratio(&self) -> f64 {
+        if self.gross_profit == 0.0 {
+            0.0
+        } else {
+            self.total_fees / self.gross_profit.abs()
+        }
+    }
+
+    /// Net profit relative to [`BacktestResult::max_drawdown`]: how many
+    /// times over the worst drawdown the run's total P&L recovered. `0.0`
+    /// with no drawdown to divide by, rather than dividing by zero.
+    pub fn recovery_factor(&self) -> f64 {
+        if self.max_drawdown == 0.0 {
+            0.0
+        } else {
+            self.total_pnl / self.max_drawdown
+        }
+    }
+
+    /// Header matching the field order of [`BacktestResult::to_csv_row`].
+    pub fn csv_header() -> &'static str {
+        "total_trades,winning_trades,total_pnl,gross_profit,gross_loss,total_fees,total_funding_paid,max_drawdown,ulcer_index"
+    }
+
+    /// One CSV row summarizing this result, in [`BacktestResult::csv_header`] order.
+    /// `run_metadata` isn't included, since it has no fixed column shape.
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.total_trades,
+            self.winning_trades,
+            self.total_pnl,
+            self.gross_profit,
+            self.gross_loss,
+            self.total_fees,
+            self.total_funding_paid,
+            self.max_drawdown,
+            self.ulcer_index
+        )
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("failed to serialize backtest result: {e}"))
+    }
+
+    pub(crate) fn from_trades(trades: &[&Trade]) -> Self {
+        let mut result = BacktestResult::default();
+        let mut equity = 0.0_f64;
+        let mut peak = 0.0_f64;
+        let mut squared_drawdowns = 0.0_f64;
+        for trade in trades {
+            result.total_trades += 1;
+            result.total_pnl += trade.pnl;
+            result.total_fees += trade.fees;
+            if trade.pnl > 0.0 {
+                result.winning_trades += 1;
+                result.gross_profit += trade.pnl;
+            } else {
+                result.gross_loss += trade.pnl;
+            }
+
+            equity += trade.pnl;
+            peak = peak.max(equity);
+            let drawdown = peak - equity;
+            result.max_drawdown = result.max_drawdown.max(drawdown);
+            squared_drawdowns += drawdown * drawdown;
+        }
+        if !trades.is_empty() {
+            result.ulcer_index = (squared_drawdowns / trades.len() as f64).sqrt();
+        }
+        result
+    }
+}
+
+/// Accumulates trades (and, in the future, equity snapshots) recorded while
+/// a backtest runs.
+#[derive(Debug, Clone, Default)]
+pub struct BacktestMetrics {
+    trades: Vec<Trade>,
+    funding_paid: f64,
+}
+
+impl BacktestMetrics {
+    pub fn new() -> Self {
+        BacktestMetrics { trades: Vec::new(), funding_paid: 0.0 }
+    }
+
+    pub fn record_trade(&mut self, trade: Trade) {
+        self.trades.push(trade);
+    }
+
+    /// Records a funding payment applied against an open position at a
+    /// funding timestamp, via [`crate::backtest::engine::BacktestEngine::add_funding_rates`].
+    /// Positive for funding paid, negative for funding received.
+    pub fn record_funding(&mut self, amount: f64) {
+        self.funding_paid += amount;
+    }
+
+    pub fn trades(&self) -> &[Trade] {
+        &self.trades
+    }
+
+    /// The aggregate result across every strategy's trades, with any
+    /// recorded funding payments folded into [`BacktestResult::total_pnl`]
+    /// and broken out as [`BacktestResult::total_funding_paid`].
+    pub fn result(&self) -> BacktestResult {
+        let mut result = BacktestResult::from_trades(&self.trades.iter().collect::<Vec<_>>());
+        result.total_funding_paid = self.funding_paid;
+        result.total_pnl -= self.funding_paid;
+        result
+    }
+
+    /// Like [`BacktestMetrics::result`], but embeds `metadata` so the
+    /// result is self-describing and re-runnable later.
+    pub fn result_with_metadata(&self, metadata: RunMetadata) -> BacktestResult {
+        let mut result = self.result();
+        result.run_metadata = Some(metadata);
+        result
+    }
+
+    /// Splits the accumulated trades by `strategy_id` and computes a
+    /// [`BacktestResult`] for each, so a multi-strategy run can report which
+    /// strategy made which trade.
+    pub fn by_strategy(&self) -> HashMap<String, BacktestResult> {
+        let mut grouped: HashMap<String, Vec<&Trade>> = HashMap::new();
+        for trade in &self.trades {
+            grouped.entry(trade.strategy_id.clone()).or_default().push(trade);
+        }
+        grouped
+            .into_iter()
+            .map(|(id, trades)| (id, BacktestResult::from_trades(&trades)))
+            .collect()
+    }
+}
+
+/// A compressed record representing one or more consecutive same-side fills
+/// that happened within the same time bucket, for cheaper reporting/charting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregatedTrade {
+    pub symbol: String,
+    pub side: Side,
+    pub size: f64,
+    /// Volume-weighted average entry price across the aggregated fills.
+    pub vwap_price: f64,
+    pub pnl: f64,
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: DateTime<Utc>,
+}
+
+/// Groups consecutive same-side trades that close within `bucket` of each
+/// other into single [`AggregatedTrade`]s (summed size, VWAP entry price,
+/// combined P&L), leaving the original `trades` slice untouched.
+pub fn aggregate_trades(trades: &[Trade], bucket: Duration) -> Vec<AggregatedTrade> {
+    let mut out: Vec<AggregatedTrade> = Vec::new();
+
+    for trade in trades {
+        let merge_with_last = out.last().is_some_and(|last| {
+            last.side == trade.side
+                && last.symbol == trade.symbol
+                && trade.closed_at.signed_duration_since(last.closed_at) <= bucket
+        });
+
+        if merge_with_last {
+            let last = out.last_mut().unwrap();
+            let combined_size = last.size + trade.size;
+            last.vwap_price =
+                (last.vwap_price * last.size + trade.entry_price * trade.size) / combined_size;
+            last.size = combined_size;
+            last.pnl += trade.pnl;
+            last.closed_at = trade.closed_at;
+        } else {
+            out.push(AggregatedTrade {
+                symbol: trade.symbol.clone(),
+                side: trade.side,
+                size: trade.size,
+                vwap_price: trade.entry_price,
+                pnl: trade.pnl,
+                opened_at: trade.opened_at,
+                closed_at: trade.closed_at,
+            });
+        }
+    }
+
+    out
+}
+
+/// Buckets each trade's PnL into fixed-width buckets keyed by the bucket's
+/// lower bound, so callers can see the distribution of per-trade returns
+/// instead of just the aggregate. A trade with an extreme PnL still lands
+/// in its own (possibly far-out) bucket rather than being dropped or
+/// clamped, so outliers remain visible in the tail. Returns an empty
+/// histogram for an empty `trades` or a non-positive `bucket_size`.
+pub fn pnl_histogram(trades: &[Trade], bucket_size: f64) -> Vec<(f64, usize)> {
+    if bucket_size <= 0.0 || trades.is_empty() {
+        return Vec::new();
+    }
+
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for trade in trades {
+        let bucket_index = (trade.pnl / bucket_size).floor() as i64;
+        *counts.entry(bucket_index).or_insert(0) += 1;
+    }
+
+    let mut buckets: Vec<(f64, usize)> =
+        counts.into_iter().map(|(index, count)| (index as f64 * bucket_size, count)).collect();
+    buckets.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    buckets
+}
+
+/// Writes one [`BacktestResult::csv_header`] row followed by one
+/// [`BacktestResult::to_csv_row`] row per result.
+pub fn write_csv(results: &[BacktestResult], path: impl AsRef<Path>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "{}", BacktestResult::csv_header())?;
+    for result in results {
+        writeln!(file, "{}", result.to_csv_row())?;
+    }
+    Ok(())
+}
+
+/// Writes every `trade` as a CSV row, one fill per line, for tools that want
+/// the raw trade log rather than the aggregated [`BacktestResult`].
+pub fn write_trades_csv(trades: &[Trade], path: impl AsRef<Path>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "symbol,side,entry_price,exit_price,size,pnl,fees,opened_at,closed_at,strategy_id")?;
+    for trade in trades {
+        writeln!(
+            file,
+            "{},{:?},{},{},{},{},{},{},{},{}",
+            trade.symbol,
+            trade.side,
+            trade.entry_price,
+            trade.exit_price,
+            trade.size,
+            trade.pnl,
+            trade.fees,
+            trade.opened_at.to_rfc3339(),
+            trade.closed_at.to_rfc3339(),
+            trade.strategy_id
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the running cumulative-P&L equity curve implied by `trades`, one
+/// row per trade close, the same running sum [`BacktestResult::from_trades`]
+/// uses to compute `max_drawdown`.
+pub fn write_equity_csv(trades: &[Trade], path: impl AsRef<Path>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "closed_at,equity")?;
+    let mut equity = 0.0_f64;
+    for trade in trades {
+        equity += trade.pnl;
+        writeln!(file, "{},{}", trade.closed_at.to_rfc3339(), equity)?;
+    }
+    Ok(())
+}
+
+/// ROI at one assumed `fee_rate`/`slippage_bps` combination, as produced by
+/// [`sensitivity_sweep`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensitivityPoint {
+    pub fee_rate: f64,
+    pub slippage_bps: f64,
+    pub roi: f64,
+}
+
+/// Re-evaluates a closed set of `trades` against a grid of hypothetical fee
+/// and slippage assumptions, so investors can see how sensitive a
+/// strategy's ROI is to worse execution costs than it actually saw. This
+/// crate has no parallel task runner to reuse, so the grid is walked
+/// sequentially — it's cheap, since no data is re-simulated, only the
+/// already-closed trades are re-priced.
+///
+/// ROI is the fee/slippage-adjusted P&L as a fraction of the total notional
+/// traded, `0.0` if `trades` is empty (nothing was traded to compare
+/// against).
+pub fn sensitivity_sweep(trades: &[Trade], fee_rates: &[f64], slippage_bps_range: &[f64]) -> Vec<SensitivityPoint> {
+    let total_notional: f64 = trades.iter().map(|trade| trade.entry_price * trade.size).sum();
+
+    let mut points = Vec::new();
+    for &fee_rate in fee_rates {
+        for &slippage_bps in slippage_bps_range {
+            let adjusted_pnl: f64 = trades
+                .iter()
+                .map(|trade| {
+                    let notional = trade.entry_price * trade.size;
+                    trade.pnl - notional * fee_rate - notional * slippage_bps / 10_000.0
+                })
+                .sum();
+            let roi = if total_notional == 0.0 { 0.0 } else { adjusted_pnl / total_notional };
+            points.push(SensitivityPoint { fee_rate, slippage_bps, roi });
+        }
+    }
+    points
+}
+
+/// Aggregate statistics across a set of Monte Carlo [`BacktestResult`]s, so
+/// callers don't have to reduce the raw vector by hand. `run.total_pnl` and
+/// `run.win_rate()` are the per-run series these are computed over; ROI
+/// isn't included since [`BacktestResult`] has no notion of starting
+/// capital to divide by.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloSummary {
+    pub mean_pnl: f64,
+    pub median_pnl: f64,
+    pub std_pnl: f64,
+    pub p5_pnl: f64,
+    pub p95_pnl: f64,
+    pub mean_max_drawdown: f64,
+    pub mean_win_rate: f64,
+    /// Fraction of runs with a negative `total_pnl`.
+    pub probability_of_loss: f64,
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+/// The value at `fraction` (e.g. `0.5` for the median) of `values` once
+/// sorted, using nearest-rank rather than interpolating between ranks.
+fn percentile(values: &[f64], fraction: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let rank = ((sorted.len() - 1) as f64 * fraction).round() as usize;
+    sorted[rank]
+}
+
+/// Reduces a set of Monte Carlo `results` into a [`MonteCarloSummary`].
+/// Every field is `0.0` for an empty `results`, rather than dividing by
+/// zero or panicking on an empty percentile lookup.
+pub fn summarize_monte_carlo(results: &[BacktestResult]) -> MonteCarloSummary {
+    let pnls: Vec<f64> = results.iter().map(|r| r.total_pnl).collect();
+    let drawdowns: Vec<f64> = results.iter().map(|r| r.max_drawdown).collect();
+    let win_rates: Vec<f64> = results.iter().map(|r| r.win_rate()).collect();
+
+    let mean_pnl = mean(&pnls);
+    let variance = if pnls.is_empty() {
+        0.0
+    } else {
+        pnls.iter().map(|pnl| (pnl - mean_pnl).powi(2)).sum::<f64>() / pnls.len() as f64
+    };
+
+    let losing_runs = results.iter().filter(|r| r.total_pnl < 0.0).count();
+
+    MonteCarloSummary {
+        mean_pnl,
+        median_pnl: percentile(&pnls, 0.5),
+        std_pnl: variance.sqrt(),
+        p5_pnl: percentile(&pnls, 0.05),
+        p95_pnl: percentile(&pnls, 0.95),
+        mean_max_drawdown: mean(&drawdowns),
+        mean_win_rate: mean(&win_rates),
+        probability_of_loss: if results.is_empty() { 0.0 } else { losing_runs as f64 / results.len() as f64 },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, AtomicOrdering::Relaxed);
+        std::env::temp_dir().join(format!("rust-trade-test-{name}-{}-{n}.csv", std::process::id()))
+    }
+
+    fn trade(strategy_id: &str, pnl: f64) -> Trade {
+        let now = Utc::now();
+        Trade {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            entry_price: 100.0,
+            exit_price: 100.0 + pnl,
+            size: 1.0,
+            pnl,
+            fees: 0.0,
+            opened_at: now,
+            closed_at: now,
+            strategy_id: strategy_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn by_strategy_attributes_trades_to_their_originating_strategy() {
+        let mut metrics = BacktestMetrics::new();
+        metrics.record_trade(trade("fast_ema", 10.0));
+        metrics.record_trade(trade("fast_ema", -2.0));
+        metrics.record_trade(trade("mean_revert", 5.0));
+
+        let by_strategy = metrics.by_strategy();
+        assert_eq!(by_strategy.len(), 2);
+        assert_eq!(by_strategy["fast_ema"].total_trades, 2);
+        assert_eq!(by_strategy["fast_ema"].total_pnl, 8.0);
+        assert_eq!(by_strategy["mean_revert"].total_trades, 1);
+        assert_eq!(by_strategy["mean_revert"].total_pnl, 5.0);
+
+        let overall = metrics.result();
+        assert_eq!(overall.total_trades, 3);
+        assert_eq!(overall.total_pnl, 13.0);
+    }
+
+    #[test]
+    fn fee_ratio_is_high_for_a_fee_heavy_result() {
+        let mut metrics = BacktestMetrics::new();
+        let mut heavy = trade("hft", 10.0);
+        heavy.fees = 8.0;
+        metrics.record_trade(heavy);
+
+        let result = metrics.result();
+        assert_eq!(result.gross_profit, 10.0);
+        assert_eq!(result.total_fees, 8.0);
+        assert!((result.fee_ratio() - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fee_ratio_is_low_for_a_fee_light_result() {
+        let mut metrics = BacktestMetrics::new();
+        let mut light = trade("swing", 100.0);
+        light.fees = 0.5;
+        metrics.record_trade(light);
+
+        let result = metrics.result();
+        assert!((result.fee_ratio() - 0.005).abs() < 1e-9);
+    }
+
+    #[test]
+    fn run_metadata_round_trips_through_json_and_matches_the_engines_settings() {
+        let mut metrics = BacktestMetrics::new();
+        metrics.record_trade(trade("fast_ema", 10.0));
+
+        let data_start = Utc::now();
+        let data_end = data_start + Duration::hours(1);
+        let metadata = RunMetadata {
+            strategy_name: "fast_ema".to_string(),
+            strategy_params: "spread_bps=5,levels=3".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            data_start,
+            data_end,
+            seed: Some(42),
+        };
+
+        let result = metrics.result_with_metadata(metadata.clone());
+        let json = serde_json::to_string(&result).unwrap();
+        let round_tripped: BacktestResult = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(round_tripped, result);
+        assert_eq!(round_tripped.run_metadata, Some(metadata));
+    }
+
+    #[test]
+    fn fee_ratio_is_zero_without_gross_profit_instead_of_dividing_by_zero() {
+        let mut metrics = BacktestMetrics::new();
+        let mut loser = trade("swing", -10.0);
+        loser.fees = 1.0;
+        metrics.record_trade(loser);
+
+        assert_eq!(metrics.result().fee_ratio(), 0.0);
+    }
+
+    fn fill(side: Side, entry_price: f64, size: f64, pnl: f64, closed_at: DateTime<Utc>) -> Trade {
+        Trade {
+            symbol: "BTCUSDT".into(),
+            side,
+            entry_price,
+            exit_price: entry_price,
+            size,
+            pnl,
+            fees: 0.0,
+            opened_at: closed_at,
+            closed_at,
+            strategy_id: "hft".into(),
+        }
+    }
+
+    #[test]
+    fn three_consecutive_buys_in_bucket_aggregate_with_vwap_price() {
+        let t0 = Utc::now();
+        let trades = vec![
+            fill(Side::Buy, 100.0, 1.0, 1.0, t0),
+            fill(Side::Buy, 102.0, 1.0, 2.0, t0 + Duration::milliseconds(100)),
+            fill(Side::Buy, 104.0, 2.0, 3.0, t0 + Duration::milliseconds(200)),
+        ];
+
+        let aggregated = aggregate_trades(&trades, Duration::seconds(1));
+        assert_eq!(aggregated.len(), 1);
+        let combined = &aggregated[0];
+        assert_eq!(combined.size, 4.0);
+        assert_eq!(combined.pnl, 6.0);
+        // VWAP = (100*1 + 102*1 + 104*2) / 4 = 102.5
+        assert!((combined.vwap_price - 102.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fills_outside_the_bucket_stay_separate() {
+        let t0 = Utc::now();
+        let trades = vec![
+            fill(Side::Buy, 100.0, 1.0, 1.0, t0),
+            fill(Side::Buy, 102.0, 1.0, 2.0, t0 + Duration::seconds(10)),
+        ];
+        let aggregated = aggregate_trades(&trades, Duration::seconds(1));
+        assert_eq!(aggregated.len(), 2);
+    }
+
+    #[test]
+    fn pnl_histogram_buckets_trades_by_their_pnl_including_a_tail_outlier() {
+        let trades: Vec<Trade> =
+            [1.0, 4.0, -1.0, -4.0, 9.5, 500.0].iter().map(|&pnl| trade("hft", pnl)).collect();
+
+        let mut histogram = pnl_histogram(&trades, 5.0);
+        histogram.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        assert_eq!(
+            histogram,
+            vec![
+                (-5.0, 2),  // -1.0, -4.0
+                (0.0, 2),   // 1.0, 4.0
+                (5.0, 1),   // 9.5
+                (500.0, 1), // 500.0 lands in its own far-out bucket, not dropped
+            ]
+        );
+    }
+
+    #[test]
+    fn max_drawdown_and_ulcer_index_match_a_hand_computed_equity_curve() {
+        // Equity after each trade: 10, 6, 16, 4. Peaks: 10, 10, 16, 16.
+        // Drawdowns: 0, 4, 0, 12.
+        let mut metrics = BacktestMetrics::new();
+        for pnl in [10.0, -4.0, 10.0, -12.0] {
+            metrics.record_trade(trade("hft", pnl));
+        }
+
+        let result = metrics.result();
+        assert_eq!(result.max_drawdown, 12.0);
+        // sqrt((0^2 + 4^2 + 0^2 + 12^2) / 4) = sqrt(160/4) = sqrt(40)
+        assert!((result.ulcer_index - 40.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recovery_factor_divides_net_profit_by_max_drawdown() {
+        let mut metrics = BacktestMetrics::new();
+        for pnl in [10.0, -4.0, 10.0, -12.0] {
+            metrics.record_trade(trade("hft", pnl));
+        }
+
+        let result = metrics.result();
+        assert_eq!(result.total_pnl, 4.0);
+        assert_eq!(result.max_drawdown, 12.0);
+        assert!((result.recovery_factor() - 4.0 / 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recovery_factor_is_zero_with_no_drawdown_instead_of_dividing_by_zero() {
+        let mut metrics = BacktestMetrics::new();
+        metrics.record_trade(trade("hft", 10.0));
+
+        let result = metrics.result();
+        assert_eq!(result.max_drawdown, 0.0);
+        assert_eq!(result.recovery_factor(), 0.0);
+    }
+
+    #[test]
+    fn pnl_histogram_is_empty_for_no_trades_or_a_non_positive_bucket_size() {
+        assert!(pnl_histogram(&[], 5.0).is_empty());
+        assert!(pnl_histogram(&[trade("hft", 1.0)], 0.0).is_empty());
+    }
+
+    #[test]
+    fn higher_fees_monotonically_reduce_roi_in_the_sensitivity_grid() {
+        let trades: Vec<Trade> = [5.0, -2.0, 8.0].iter().map(|&pnl| trade("hft", pnl)).collect();
+        let fee_rates = [0.0, 0.001, 0.005, 0.01];
+
+        let points = sensitivity_sweep(&trades, &fee_rates, &[0.0]);
+        assert_eq!(points.len(), fee_rates.len());
+
+        let rois: Vec<f64> = points.iter().map(|p| p.roi).collect();
+        for window in rois.windows(2) {
+            assert!(window[0] > window[1], "ROI should strictly decrease as fee_rate increases: {rois:?}");
+        }
+    }
+
+    #[test]
+    fn sensitivity_sweep_is_zero_roi_with_no_trades() {
+        let points = sensitivity_sweep(&[], &[0.0, 0.01], &[0.0]);
+        assert!(points.iter().all(|p| p.roi == 0.0));
+    }
+
+    fn result_with(total_pnl: f64, max_drawdown: f64, win_rate_trades: (usize, usize)) -> BacktestResult {
+        BacktestResult {
+            total_trades: win_rate_trades.1,
+            winning_trades: win_rate_trades.0,
+            total_pnl,
+            max_drawdown,
+            ..BacktestResult::default()
+        }
+    }
+
+    #[test]
+    fn summarize_monte_carlo_computes_mean_median_std_and_percentiles() {
+        // Deterministic runs: pnl = 10, 20, 30, -40, -50; two losers out of five.
+        let results = vec![
+            result_with(10.0, 5.0, (1, 2)),
+            result_with(20.0, 10.0, (1, 2)),
+            result_with(30.0, 0.0, (2, 2)),
+            result_with(-40.0, 20.0, (0, 2)),
+            result_with(-50.0, 25.0, (0, 2)),
+        ];
+
+        let summary = summarize_monte_carlo(&results);
+
+        assert!((summary.mean_pnl - (-6.0)).abs() < 1e-9);
+        assert_eq!(summary.median_pnl, 10.0);
+        assert_eq!(summary.probability_of_loss, 0.4);
+        assert!((summary.mean_max_drawdown - 12.0).abs() < 1e-9);
+        assert!((summary.mean_win_rate - 0.4).abs() < 1e-9);
+        // p5/p95 use nearest-rank over the sorted series [-50, -40, 10, 20, 30].
+        assert_eq!(summary.p5_pnl, -50.0);
+        assert_eq!(summary.p95_pnl, 30.0);
+    }
+
+    #[test]
+    fn to_json_round_trips_to_an_equal_result() {
+        let result = result_with(4.0, 12.0, (1, 2));
+        let json = result.to_json().unwrap();
+        let round_tripped: BacktestResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, result);
+    }
+
+    #[test]
+    fn write_csv_round_trips_the_header_and_first_row() {
+        let path = scratch_path("results");
+        let results = vec![result_with(4.0, 12.0, (1, 2)), result_with(-10.0, 20.0, (0, 1))];
+
+        write_csv(&results, &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let mut lines = contents.lines();
+        assert_eq!(lines.next().unwrap(), BacktestResult::csv_header());
+        assert_eq!(lines.next().unwrap(), results[0].to_csv_row());
+        assert_eq!(lines.next().unwrap(), results[1].to_csv_row());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn write_equity_csv_reports_the_running_cumulative_pnl() {
+        let path = scratch_path("equity");
+        let trades: Vec<Trade> = [10.0, -4.0, 10.0].iter().map(|&pnl| trade("hft", pnl)).collect();
+
+        write_equity_csv(&trades, &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let rows: Vec<&str> = contents.lines().skip(1).collect();
+        let equities: Vec<f64> = rows.iter().map(|row| row.split(',').nth(1).unwrap().parse().unwrap()).collect();
+        assert_eq!(equities, vec![10.0, 6.0, 16.0]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn summarize_monte_carlo_is_all_zero_for_no_runs() {
+        let summary = summarize_monte_carlo(&[]);
+        assert_eq!(
+            summary,
+            MonteCarloSummary {
+                mean_pnl: 0.0,
+                median_pnl: 0.0,
+                std_pnl: 0.0,
+                p5_pnl: 0.0,
+                p95_pnl: 0.0,
+                mean_max_drawdown: 0.0,
+                mean_win_rate: 0.0,
+                probability_of_loss: 0.0,
+            }
+        );
+    }
+}
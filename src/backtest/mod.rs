@@ -0,0 +1,9 @@
+pub mod bin_format;
+pub mod commission;
+pub mod emulator;
+pub mod engine;
+pub mod feed_reliability;
+pub mod leverage;
+pub mod metrics;
+pub mod orderbook;
+pub mod replay;
@@ -0,0 +1,135 @@
+//! A price-level order book snapshot, used to decide how a marketable
+//! order fills against resting liquidity instead of assuming it all fills
+//! at one price.
+
+use crate::models::Side;
+
+/// One price level's available size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// A book snapshot's two sides, each ordered best price first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrderBook {
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+impl OrderBook {
+    pub fn new(bids: Vec<PriceLevel>, asks: Vec<PriceLevel>) -> Self {
+        OrderBook { bids, asks }
+    }
+
+    /// The levels a marketable order on `side` takes liquidity from: a buy
+    /// lifts the asks, a sell hits the bids.
+    fn levels_for(&self, side: Side) -> &[PriceLevel] {
+        match side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        }
+    }
+}
+
+/// The outcome of [`FillModel::fill`]: how much of the order filled, at
+/// what size-weighted average price, and how much is left over.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillOutcome {
+    pub filled_size: f64,
+    pub avg_price: f64,
+    pub remaining_size: f64,
+}
+
+/// How a marketable order is filled against an [`OrderBook`] snapshot.
+/// Once a [`FillModel::Partial`] fill leaves a remainder, the caller should
+/// rest it via [`crate::backtest::emulator::MarketEmulator::place_order`]
+/// and track it through [`crate::backtest::emulator::MarketEmulator::fill_order`]
+/// as more liquidity arrives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillModel {
+    /// Fills the full requested size at the best price, ignoring how much
+    /// liquidity is actually resting there.
+    AllOrNothing,
+    /// Fills proportionally to the liquidity resting at each price level,
+    /// walking the book until the order is filled or liquidity runs out,
+    /// leaving any unfilled remainder to rest.
+    Partial,
+}
+
+impl FillModel {
+    /// Fills an order for `size` on `side` against `book`.
+    pub fn fill(&self, book: &OrderBook, side: Side, size: f64) -> FillOutcome {
+        match self {
+            FillModel::AllOrNothing => {
+                let avg_price = book.levels_for(side).first().map(|level| level.price).unwrap_or(0.0);
+                FillOutcome { filled_size: size, avg_price, remaining_size: 0.0 }
+            }
+            FillModel::Partial => {
+                let mut remaining = size;
+                let mut filled = 0.0_f64;
+                let mut notional = 0.0_f64;
+                for level in book.levels_for(side) {
+                    if remaining <= 0.0 {
+                        break;
+                    }
+                    let take = remaining.min(level.size);
+                    filled += take;
+                    notional += take * level.price;
+                    remaining -= take;
+                }
+                let avg_price = if filled > 0.0 { notional / filled } else { 0.0 };
+                FillOutcome { filled_size: filled, avg_price, remaining_size: remaining.max(0.0) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> OrderBook {
+        OrderBook::new(
+            vec![],
+            vec![
+                PriceLevel { price: 100.0, size: 1.0 },
+                PriceLevel { price: 101.0, size: 2.0 },
+                PriceLevel { price: 102.0, size: 5.0 },
+            ],
+        )
+    }
+
+    #[test]
+    fn all_or_nothing_fills_the_full_size_at_the_best_price_regardless_of_depth() {
+        let outcome = FillModel::AllOrNothing.fill(&book(), Side::Buy, 10.0);
+        assert_eq!(outcome, FillOutcome { filled_size: 10.0, avg_price: 100.0, remaining_size: 0.0 });
+    }
+
+    #[test]
+    fn partial_fills_across_levels_with_a_correct_weighted_average_price_when_liquidity_is_sufficient() {
+        // 1.0 @ 100 + 2.0 @ 101 = 3.0 size, spending 1.0*100 + 2.0*101 = 302 notional.
+        let outcome = FillModel::Partial.fill(&book(), Side::Buy, 3.0);
+        assert_eq!(outcome.filled_size, 3.0);
+        assert!((outcome.avg_price - 302.0 / 3.0).abs() < 1e-9);
+        assert_eq!(outcome.remaining_size, 0.0);
+    }
+
+    #[test]
+    fn partial_leaves_a_remainder_when_an_order_exceeds_all_available_liquidity() {
+        // Book only has 1.0 + 2.0 + 5.0 = 8.0 total size; ordering 10.0 leaves 2.0 resting.
+        let outcome = FillModel::Partial.fill(&book(), Side::Buy, 10.0);
+        assert_eq!(outcome.filled_size, 8.0);
+        assert_eq!(outcome.remaining_size, 2.0);
+        let expected_notional = 1.0 * 100.0 + 2.0 * 101.0 + 5.0 * 102.0;
+        assert!((outcome.avg_price - expected_notional / 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn an_empty_side_fills_nothing_and_leaves_the_full_size_resting() {
+        let empty = OrderBook::new(vec![], vec![]);
+        let outcome = FillModel::Partial.fill(&empty, Side::Buy, 5.0);
+        assert_eq!(outcome, FillOutcome { filled_size: 0.0, avg_price: 0.0, remaining_size: 5.0 });
+    }
+}
@@ -0,0 +1,563 @@
+//! Replays a recorded stream of [`TradeTick`]s in order, as the source of
+//! ticks fed into a [`super::engine::BacktestEngine`] run.
+
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::bin_format::BinFileReader;
+use crate::models::{Side, TradeTick};
+
+/// An in-memory, already-ordered sequence of ticks with a cursor, so a
+/// backtest can pull ticks one at a time without re-reading the source file.
+#[derive(Debug, Clone)]
+pub struct TradeStream {
+    ticks: Vec<TradeTick>,
+    next_index: usize,
+}
+
+impl TradeStream {
+    pub fn new(ticks: Vec<TradeTick>) -> Self {
+        TradeStream { ticks, next_index: 0 }
+    }
+
+    /// Returns the next tick and advances the cursor, or `None` once the
+    /// stream is exhausted.
+    pub fn next_tick(&mut self) -> Option<&TradeTick> {
+        let tick = self.ticks.get(self.next_index)?;
+        self.next_index += 1;
+        Some(tick)
+    }
+
+    /// Returns the next tick without advancing the cursor, so a caller
+    /// merging several streams can compare timestamps before committing to
+    /// one.
+    pub fn peek_tick(&self) -> Option<&TradeTick> {
+        self.ticks.get(self.next_index)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.ticks.len() - self.next_index
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.next_index >= self.ticks.len()
+    }
+
+    /// Returns a fresh [`TradeStream`] containing only the ticks with
+    /// `start <= ts_ns < end`, with its own cursor reset to the start, so a
+    /// sub-period backtest (e.g. a crash event, a walk-forward window) can
+    /// run without reloading the source file.
+    pub fn slice_by_time(&self, start: i64, end: i64) -> TradeStream {
+        let ticks = self.ticks.iter().filter(|tick| tick.ts_ns >= start && tick.ts_ns < end).copied().collect();
+        TradeStream::new(ticks)
+    }
+
+    /// The `[first_tick_ts_ns, last_tick_ts_ns]` span covered by this
+    /// stream, or `None` if it's empty. Ticks are assumed already ordered,
+    /// as the rest of this type does.
+    pub fn time_bounds(&self) -> Option<(i64, i64)> {
+        Some((self.ticks.first()?.ts_ns, self.ticks.last()?.ts_ns))
+    }
+
+    /// Synthesizes a tick stream from `candles`, for strategies that only
+    /// need candle-granularity data rather than genuine tick-by-tick
+    /// history. Each candle becomes four ticks along its open -> high ->
+    /// low -> close path, evenly spaced across [`Candle::interval_ns`],
+    /// with its volume split evenly across the four legs. Each leg's side
+    /// is `Buy` if price rose or held from the previous point in the path
+    /// and `Sell` if it fell; the first leg is the open compared with
+    /// itself, so it is always `Buy`.
+    pub fn from_candles(candles: &[Candle]) -> TradeStream {
+        let mut ticks = Vec::with_capacity(candles.len() * 4);
+        for candle in candles {
+            let leg_ns = candle.interval_ns / 4;
+            let leg_size = candle.volume / 4.0;
+            let mut prev = candle.open;
+            for (i, &price) in [candle.open, candle.high, candle.low, candle.close].iter().enumerate() {
+                let side = if price >= prev { Side::Buy } else { Side::Sell };
+                ticks.push(TradeTick {
+                    ts_ns: candle.ts_ns + leg_ns * i as i64,
+                    price,
+                    size: leg_size,
+                    side,
+                    best_bid: None,
+                    best_ask: None,
+                });
+                prev = price;
+            }
+        }
+        TradeStream::new(ticks)
+    }
+}
+
+/// One OHLCV candle, the interval summary many data providers offer in
+/// place of full tick-level data, as fed into [`TradeStream::from_candles`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    pub ts_ns: i64,
+    /// How long this candle spans; ticks synthesized from it are spaced
+    /// across this interval.
+    pub interval_ns: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Loads recorded tick streams from `.bin` files.
+pub struct ReplayEngine;
+
+impl ReplayEngine {
+    /// Reads every record in `path` and returns them as a ready-to-replay
+    /// [`TradeStream`], in the order they were recorded.
+    pub fn load_bin_file(path: impl AsRef<Path>) -> io::Result<TradeStream> {
+        let mut reader = BinFileReader::open(path)?;
+        let ticks = reader.read_all()?.into_iter().map(|record| record.to_tick()).collect();
+        Ok(TradeStream::new(ticks))
+    }
+
+    /// Merges `streams` (e.g. one per symbol) into one time-ordered,
+    /// time-windowed, wall-clock-paced sequence: a k-way merge via
+    /// [`MultiStreamReplay`], restricted to `settings.start_time`/`end_time`
+    /// and paced per `settings.speed_multiplier` the same way
+    /// [`PacedReplay`] paces a single stream. For cross-symbol strategies
+    /// that need every stream fed in one global order.
+    pub fn merged_iter<S: Fn(Duration)>(
+        streams: Vec<TradeStream>,
+        settings: ReplaySettings,
+        sleep: S,
+    ) -> PacedMultiStreamReplay<S> {
+        let (start, end) = settings.window_ns();
+        let windowed = streams.into_iter().map(|stream| stream.slice_by_time(start, end)).collect();
+        PacedMultiStreamReplay {
+            merge: MultiStreamReplay::new(windowed, TieBreak::FirstIndexWins),
+            settings,
+            sleep,
+            last_ts_ns: None,
+        }
+    }
+}
+
+/// Controls how [`PacedReplay`] paces ticks against wall-clock time, and
+/// which ticks are in scope at all.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ReplaySettings {
+    /// `0.0` (or negative) means no pacing at all: ticks are yielded as
+    /// fast as the caller pulls them, with no sleeping — the mode
+    /// backtests want, since they have no wall clock to keep up with. Any
+    /// positive value is a real-time multiplier — `1.0` paces at the
+    /// stream's original recorded rate, `2.0` plays it back twice as fast,
+    /// `0.5` twice as slow.
+    pub speed_multiplier: f64,
+    /// Ticks before this are skipped entirely. `None` means from the
+    /// start of the stream.
+    #[serde(default)]
+    pub start_time: Option<DateTime<Utc>>,
+    /// Ticks after this are skipped entirely. `None` means to the end of
+    /// the stream.
+    #[serde(default)]
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+/// Mirrors [`ReplaySettings`] but rejects an unrecognized field by name
+/// instead of silently ignoring it, for [`ReplaySettings::parse_strict`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictReplaySettings {
+    speed_multiplier: f64,
+    #[serde(default)]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    end_time: Option<DateTime<Utc>>,
+}
+
+impl ReplaySettings {
+    pub fn as_fast_as_possible() -> Self {
+        ReplaySettings { speed_multiplier: 0.0, start_time: None, end_time: None }
+    }
+
+    pub fn real_time() -> Self {
+        ReplaySettings { speed_multiplier: 1.0, start_time: None, end_time: None }
+    }
+
+    /// Parses user-supplied JSON leniently: an unrecognized field (e.g. a
+    /// typo'd key) is silently dropped, matching `serde_json`'s default.
+    pub fn parse_lenient(raw: &str) -> Result<Self, String> {
+        serde_json::from_str(raw).map_err(|e| format!("invalid replay settings: {e}"))
+    }
+
+    /// Like [`ReplaySettings::parse_lenient`], but rejects an unrecognized
+    /// field by name rather than dropping it, so a typo in a hand-written
+    /// config doesn't silently fall back to the default.
+    pub fn parse_strict(raw: &str) -> Result<Self, String> {
+        let strict: StrictReplaySettings =
+            serde_json::from_str(raw).map_err(|e| format!("invalid replay settings: {e}"))?;
+        Ok(ReplaySettings {
+            speed_multiplier: strict.speed_multiplier,
+            start_time: strict.start_time,
+            end_time: strict.end_time,
+        })
+    }
+
+    /// The `[start, end)` nanosecond window `start_time`/`end_time` imply,
+    /// with an absent bound widened to cover the whole `i64` range.
+    fn window_ns(&self) -> (i64, i64) {
+        let start = self.start_time.and_then(|t| t.timestamp_nanos_opt()).unwrap_or(i64::MIN);
+        let end = self.end_time.and_then(|t| t.timestamp_nanos_opt()).unwrap_or(i64::MAX);
+        (start, end.saturating_add(1)) // end_time is inclusive; slice_by_time's upper bound isn't.
+    }
+}
+
+/// Sleeps (per `settings.speed_multiplier`) for however long should
+/// separate `ts_ns` from the previous tick passed here, then records it as
+/// the new previous tick. Shared by [`PacedReplay`] and
+/// [`PacedMultiStreamReplay`] so both pace identically.
+fn sleep_for_gap<S: Fn(Duration)>(settings: &ReplaySettings, last_ts_ns: &mut Option<i64>, ts_ns: i64, sleep: &S) {
+    if settings.speed_multiplier > 0.0 {
+        if let Some(last) = *last_ts_ns {
+            let gap_ns = (ts_ns - last).max(0) as f64;
+            let wait_ns = (gap_ns / settings.speed_multiplier).round() as u64;
+            sleep(Duration::from_nanos(wait_ns));
+        }
+    }
+    *last_ts_ns = Some(ts_ns);
+}
+
+/// Paces a [`TradeStream`] against wall-clock time per [`ReplaySettings`],
+/// sleeping between ticks via an injected `sleep` so tests don't have to
+/// wait out real delays.
+pub struct PacedReplay<'a, S: Fn(Duration)> {
+    stream: &'a mut TradeStream,
+    settings: ReplaySettings,
+    sleep: S,
+    last_ts_ns: Option<i64>,
+}
+
+impl<'a, S: Fn(Duration)> PacedReplay<'a, S> {
+    pub fn new(stream: &'a mut TradeStream, settings: ReplaySettings, sleep: S) -> Self {
+        PacedReplay { stream, settings, sleep, last_ts_ns: None }
+    }
+
+    /// Returns the next tick, first sleeping for however long
+    /// `speed_multiplier` says should separate it from the previous one.
+    /// The very first tick is never delayed; there's nothing to pace it
+    /// against. `speed_multiplier <= 0.0` never sleeps at all, for a
+    /// backtest that wants ticks as fast as it can pull them.
+    pub fn next_tick(&mut self) -> Option<TradeTick> {
+        let tick = *self.stream.next_tick()?;
+        sleep_for_gap(&self.settings, &mut self.last_ts_ns, tick.ts_ns, &self.sleep);
+        Some(tick)
+    }
+}
+
+/// Deterministic policy for which stream wins when two or more of
+/// [`MultiStreamReplay`]'s streams have a next tick with the exact same
+/// timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The lowest-indexed tied stream always wins. This is the behavior a
+    /// plain `<` comparison over stream index order gives implicitly, made
+    /// explicit and selectable here.
+    FirstIndexWins,
+    /// Ties rotate through the tied streams by a cursor that advances on
+    /// every tie, so repeated tied timestamps don't always favor the same
+    /// stream.
+    RoundRobin,
+}
+
+/// Merges several already-ordered [`TradeStream`]s into one time-ordered
+/// sequence, tagging each tick with the index of the stream it came from.
+/// Exactly which stream wins an exact-timestamp tie is controlled by
+/// `tie_break`, rather than left to whichever stream happens to be indexed
+/// first.
+pub struct MultiStreamReplay {
+    streams: Vec<TradeStream>,
+    tie_break: TieBreak,
+    round_robin_cursor: usize,
+}
+
+impl MultiStreamReplay {
+    pub fn new(streams: Vec<TradeStream>, tie_break: TieBreak) -> Self {
+        MultiStreamReplay { streams, tie_break, round_robin_cursor: 0 }
+    }
+
+    /// Returns the `(stream_index, tick)` with the earliest timestamp across
+    /// every stream, advancing that stream's cursor. Ties are broken per
+    /// `tie_break`. Returns `None` once every stream is exhausted.
+    pub fn next_tick_with_lag(&mut self) -> Option<(usize, TradeTick)> {
+        let candidates: Vec<(usize, TradeTick)> = self
+            .streams
+            .iter()
+            .enumerate()
+            .filter_map(|(i, stream)| stream.peek_tick().map(|tick| (i, *tick)))
+            .collect();
+        let min_ts = candidates.iter().map(|(_, tick)| tick.ts_ns).min()?;
+        let tied: Vec<usize> = candidates
+            .iter()
+            .filter(|(_, tick)| tick.ts_ns == min_ts)
+            .map(|(i, _)| *i)
+            .collect();
+
+        let winner = match self.tie_break {
+            TieBreak::FirstIndexWins => tied[0],
+            TieBreak::RoundRobin => {
+                let chosen = tied[self.round_robin_cursor % tied.len()];
+                self.round_robin_cursor = self.round_robin_cursor.wrapping_add(1);
+                chosen
+            }
+        };
+
+        let tick = *self.streams[winner].next_tick().expect("winner was peeked above");
+        Some((winner, tick))
+    }
+}
+
+/// Paces [`MultiStreamReplay`]'s merged output against wall-clock time, per
+/// [`ReplaySettings::speed_multiplier`], built by [`ReplayEngine::merged_iter`].
+pub struct PacedMultiStreamReplay<S: Fn(Duration)> {
+    merge: MultiStreamReplay,
+    settings: ReplaySettings,
+    sleep: S,
+    last_ts_ns: Option<i64>,
+}
+
+impl<S: Fn(Duration)> PacedMultiStreamReplay<S> {
+    /// Returns the `(stream_index, tick)` with the earliest timestamp
+    /// across every stream, first sleeping for however long
+    /// `speed_multiplier` says should separate it from the previous one.
+    /// See [`PacedReplay::next_tick`] for the pacing rules.
+    pub fn next_tick(&mut self) -> Option<(usize, TradeTick)> {
+        let (index, tick) = self.merge.next_tick_with_lag()?;
+        sleep_for_gap(&self.settings, &mut self.last_ts_ns, tick.ts_ns, &self.sleep);
+        Some((index, tick))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_tick_yields_ticks_in_order_then_none() {
+        let mut stream = TradeStream::new(vec![
+            TradeTick {
+                ts_ns: 1,
+                price: 100.0,
+                size: 1.0,
+                side: crate::models::Side::Buy,
+                best_bid: None,
+                best_ask: None,
+            },
+            TradeTick {
+                ts_ns: 2,
+                price: 101.0,
+                size: 1.0,
+                side: crate::models::Side::Sell,
+                best_bid: None,
+                best_ask: None,
+            },
+        ]);
+
+        assert_eq!(stream.next_tick().unwrap().ts_ns, 1);
+        assert_eq!(stream.remaining(), 1);
+        assert_eq!(stream.next_tick().unwrap().ts_ns, 2);
+        assert!(stream.next_tick().is_none());
+        assert!(stream.is_exhausted());
+    }
+
+    #[test]
+    fn from_candles_synthesizes_ticks_along_the_open_high_low_close_path_in_order() {
+        let candles = vec![
+            Candle { ts_ns: 0, interval_ns: 4_000, open: 100.0, high: 105.0, low: 98.0, close: 102.0, volume: 8.0 },
+            Candle {
+                ts_ns: 10_000,
+                interval_ns: 4_000,
+                open: 102.0,
+                high: 103.0,
+                low: 99.0,
+                close: 101.0,
+                volume: 4.0,
+            },
+        ];
+
+        let mut stream = TradeStream::from_candles(&candles);
+        assert_eq!(stream.remaining(), 8);
+
+        let prices: Vec<f64> = std::iter::from_fn(|| stream.next_tick().map(|t| t.price)).collect();
+        assert_eq!(prices, vec![100.0, 105.0, 98.0, 102.0, 102.0, 103.0, 99.0, 101.0]);
+
+        let mut stream = TradeStream::from_candles(&candles);
+        let timestamps: Vec<i64> = std::iter::from_fn(|| stream.next_tick().map(|t| t.ts_ns)).collect();
+        assert_eq!(timestamps, vec![0, 1_000, 2_000, 3_000, 10_000, 11_000, 12_000, 13_000]);
+
+        let mut stream = TradeStream::from_candles(&candles[..1]);
+        let sides: Vec<crate::models::Side> = std::iter::from_fn(|| stream.next_tick().map(|t| t.side)).collect();
+        // open(100) -> high(105): up, high(105) -> low(98): down, low(98) -> close(102): up.
+        assert_eq!(
+            sides,
+            vec![crate::models::Side::Buy, crate::models::Side::Buy, crate::models::Side::Sell, crate::models::Side::Buy]
+        );
+    }
+
+    #[test]
+    fn slice_by_time_keeps_only_ticks_in_the_window_in_order() {
+        let ticks: Vec<TradeTick> = (0..10)
+            .map(|i| TradeTick {
+                ts_ns: i,
+                price: 100.0,
+                size: 1.0,
+                side: crate::models::Side::Buy,
+                best_bid: None,
+                best_ask: None,
+            })
+            .collect();
+        let stream = TradeStream::new(ticks);
+
+        let mut sliced = stream.slice_by_time(3, 7);
+
+        let ts: Vec<i64> = std::iter::from_fn(|| sliced.next_tick().map(|t| t.ts_ns)).collect();
+        assert_eq!(ts, vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn time_bounds_spans_the_first_and_last_tick() {
+        let stream = TradeStream::new(vec![tick_at(10), tick_at(20), tick_at(30)]);
+        assert_eq!(stream.time_bounds(), Some((10, 30)));
+    }
+
+    #[test]
+    fn time_bounds_is_none_for_an_empty_stream() {
+        let stream = TradeStream::new(vec![]);
+        assert_eq!(stream.time_bounds(), None);
+    }
+
+    fn tick_at(ts_ns: i64) -> TradeTick {
+        TradeTick { ts_ns, price: 100.0, size: 1.0, side: crate::models::Side::Buy, best_bid: None, best_ask: None }
+    }
+
+    #[test]
+    fn speed_zero_never_sleeps_regardless_of_the_gap_between_ticks() {
+        let mut stream = TradeStream::new(vec![tick_at(0), tick_at(1_000_000_000), tick_at(5_000_000_000)]);
+        let total_slept = std::cell::Cell::new(Duration::ZERO);
+        let mut replay = PacedReplay::new(&mut stream, ReplaySettings::as_fast_as_possible(), |d| {
+            total_slept.set(total_slept.get() + d);
+        });
+
+        while replay.next_tick().is_some() {}
+        assert_eq!(total_slept.get(), Duration::ZERO);
+    }
+
+    #[test]
+    fn speed_two_paces_at_half_the_streams_recorded_gaps() {
+        let mut stream = TradeStream::new(vec![tick_at(0), tick_at(1_000_000_000)]);
+        let total_slept = std::cell::Cell::new(Duration::ZERO);
+        let mut replay = PacedReplay::new(&mut stream, ReplaySettings { speed_multiplier: 2.0, ..Default::default() }, |d| {
+            total_slept.set(total_slept.get() + d);
+        });
+
+        replay.next_tick().unwrap(); // first tick: no prior tick to pace against
+        assert_eq!(total_slept.get(), Duration::ZERO);
+        replay.next_tick().unwrap(); // 1s recorded gap at 2x speed -> 500ms
+        assert_eq!(total_slept.get(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn parse_strict_rejects_a_typod_field_that_parse_lenient_silently_ignores() {
+        let raw = r#"{"speed_multiplier": 2.0, "speedd_multiplier": 99.0}"#;
+
+        let lenient = ReplaySettings::parse_lenient(raw).unwrap();
+        assert_eq!(lenient, ReplaySettings { speed_multiplier: 2.0, ..Default::default() });
+
+        let strict_err = ReplaySettings::parse_strict(raw).unwrap_err();
+        assert!(strict_err.contains("speedd_multiplier"), "error should name the typo'd field: {strict_err}");
+    }
+
+    #[test]
+    fn parse_strict_accepts_a_well_formed_config() {
+        let raw = r#"{"speed_multiplier": 0.5}"#;
+        assert_eq!(ReplaySettings::parse_strict(raw).unwrap(), ReplaySettings { speed_multiplier: 0.5, ..Default::default() });
+    }
+
+    #[test]
+    fn first_index_wins_always_favors_the_lower_indexed_stream_on_a_tie() {
+        let streams = vec![
+            TradeStream::new(vec![tick_at(100), tick_at(100)]),
+            TradeStream::new(vec![tick_at(100), tick_at(100)]),
+        ];
+        let mut replay = MultiStreamReplay::new(streams, TieBreak::FirstIndexWins);
+
+        let winners: Vec<usize> = std::iter::from_fn(|| replay.next_tick_with_lag().map(|(i, _)| i)).collect();
+        assert_eq!(winners, vec![0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn round_robin_rotates_through_tied_streams_instead_of_always_picking_the_same_one() {
+        let streams = vec![
+            TradeStream::new(vec![tick_at(100), tick_at(100)]),
+            TradeStream::new(vec![tick_at(100), tick_at(100)]),
+        ];
+        let mut replay = MultiStreamReplay::new(streams, TieBreak::RoundRobin);
+
+        let winners: Vec<usize> = std::iter::from_fn(|| replay.next_tick_with_lag().map(|(i, _)| i)).collect();
+        assert_eq!(winners, vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn non_tied_ticks_are_still_emitted_in_strict_timestamp_order() {
+        let streams = vec![
+            TradeStream::new(vec![tick_at(100), tick_at(300)]),
+            TradeStream::new(vec![tick_at(200)]),
+        ];
+        let mut replay = MultiStreamReplay::new(streams, TieBreak::RoundRobin);
+
+        let order: Vec<i64> = std::iter::from_fn(|| replay.next_tick_with_lag().map(|(_, tick)| tick.ts_ns)).collect();
+        assert_eq!(order, vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn merged_iter_interleaves_two_streams_in_monotonic_global_order() {
+        let streams = vec![
+            TradeStream::new(vec![tick_at(100), tick_at(300), tick_at(500)]),
+            TradeStream::new(vec![tick_at(200), tick_at(400)]),
+        ];
+        let mut replay = ReplayEngine::merged_iter(streams, ReplaySettings::as_fast_as_possible(), |_| {});
+
+        let order: Vec<i64> = std::iter::from_fn(|| replay.next_tick().map(|(_, tick)| tick.ts_ns)).collect();
+        assert_eq!(order, vec![100, 200, 300, 400, 500]);
+        assert!(order.is_sorted());
+    }
+
+    #[test]
+    fn speed_zero_replays_an_hour_long_span_in_well_under_a_second_of_wall_clock_time() {
+        let ticks: Vec<TradeTick> = (0..3_600).map(|s| tick_at(s * 1_000_000_000)).collect();
+        let mut stream = TradeStream::new(ticks);
+        let mut replay = PacedReplay::new(&mut stream, ReplaySettings::as_fast_as_possible(), std::thread::sleep);
+
+        let started = std::time::Instant::now();
+        while replay.next_tick().is_some() {}
+        assert!(
+            started.elapsed() < Duration::from_secs(1),
+            "speed_multiplier = 0.0 should never sleep, took {:?}",
+            started.elapsed()
+        );
+    }
+
+    #[test]
+    fn merged_iter_drops_ticks_outside_the_start_and_end_time_window() {
+        let streams = vec![TradeStream::new(vec![tick_at(1_000), tick_at(2_000), tick_at(3_000)])];
+        let settings = ReplaySettings {
+            start_time: Some(DateTime::from_timestamp_nanos(1_500)),
+            end_time: Some(DateTime::from_timestamp_nanos(2_500)),
+            ..ReplaySettings::as_fast_as_possible()
+        };
+        let mut replay = ReplayEngine::merged_iter(streams, settings, |_| {});
+
+        let order: Vec<i64> = std::iter::from_fn(|| replay.next_tick().map(|(_, tick)| tick.ts_ns)).collect();
+        assert_eq!(order, vec![2_000]);
+    }
+}
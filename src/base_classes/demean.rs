@@ -0,0 +1,105 @@
+//! Tracks each venue's price offset from the cross-venue mean, so strategies
+//! comparing BBOs across exchanges aren't thrown off by a venue that's
+//! consistently quoting a bit high or low (e.g. a funding-driven basis).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::base_classes::feed_gate::ExchangeFeed;
+
+/// The demean window `spawn_state_engine` falls back to when `EngineConfig`
+/// doesn't override it.
+pub const DEFAULT_DEMEAN_HALF_LIFE: Duration = Duration::from_secs(8);
+
+/// An exponentially-weighted per-venue price offset from the cross-venue
+/// mean, decaying toward new observations over a configurable half-life.
+/// A shorter half-life adapts faster to a widening basis but is noisier; a
+/// longer one is smoother but slower to track fast-moving markets.
+#[derive(Debug, Clone)]
+pub struct DemeanTracker {
+    half_life: Duration,
+    adjustment: HashMap<ExchangeFeed, f64>,
+}
+
+impl DemeanTracker {
+    pub fn new(half_life: Duration) -> Self {
+        DemeanTracker {
+            half_life,
+            adjustment: HashMap::new(),
+        }
+    }
+
+    pub fn half_life(&self) -> Duration {
+        self.half_life
+    }
+
+    /// The current price adjustment (`mid - cross_venue_mean`) for `feed`,
+    /// or zero if no observation has been recorded yet.
+    pub fn adjustment(&self, feed: ExchangeFeed) -> f64 {
+        *self.adjustment.get(&feed).unwrap_or(&0.0)
+    }
+
+    /// Records one observation for `feed`: `mid` is its instantaneous mid
+    /// price, `cross_venue_mean` the mean mid across every tracked venue at
+    /// the same instant, and `elapsed` the time since the previous
+    /// observation for this feed (any value works for the first).
+    pub fn observe(&mut self, feed: ExchangeFeed, mid: f64, cross_venue_mean: f64, elapsed: Duration) {
+        let offset = mid - cross_venue_mean;
+        let weight = decay_weight(elapsed, self.half_life);
+        let previous = self.adjustment(feed);
+        self.adjustment.insert(feed, previous * weight + offset * (1.0 - weight));
+    }
+}
+
+/// How much weight the previous adjustment keeps after `elapsed` has passed,
+/// given a half-life of `half_life`. A zero half-life snaps to the latest
+/// observation immediately.
+fn decay_weight(elapsed: Duration, half_life: Duration) -> f64 {
+    if half_life.is_zero() {
+        return 0.0;
+    }
+    0.5f64.powf(elapsed.as_secs_f64() / half_life.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unobserved_feed_has_zero_adjustment() {
+        let tracker = DemeanTracker::new(Duration::from_secs(8));
+        assert_eq!(tracker.adjustment(ExchangeFeed::Kraken), 0.0);
+    }
+
+    #[test]
+    fn two_venues_with_a_constant_offset_converge_to_it_within_the_window() {
+        let half_life = Duration::from_millis(100);
+        let mut tracker = DemeanTracker::new(half_life);
+
+        // Kraken consistently quotes 2.0 above Coinbase; feed both venues
+        // the same constant offset once per tick for several half-lives.
+        for _ in 0..20 {
+            let mean = 100.0;
+            tracker.observe(ExchangeFeed::Kraken, 101.0, mean, half_life);
+            tracker.observe(ExchangeFeed::Coinbase, 99.0, mean, half_life);
+        }
+
+        assert!(
+            (tracker.adjustment(ExchangeFeed::Kraken) - 1.0).abs() < 0.01,
+            "expected Kraken's adjustment to converge near +1.0, got {}",
+            tracker.adjustment(ExchangeFeed::Kraken)
+        );
+        assert!(
+            (tracker.adjustment(ExchangeFeed::Coinbase) - (-1.0)).abs() < 0.01,
+            "expected Coinbase's adjustment to converge near -1.0, got {}",
+            tracker.adjustment(ExchangeFeed::Coinbase)
+        );
+    }
+
+    #[test]
+    fn a_zero_half_life_snaps_immediately_to_the_latest_observation() {
+        let mut tracker = DemeanTracker::new(Duration::ZERO);
+        tracker.observe(ExchangeFeed::Kraken, 101.0, 100.0, Duration::from_secs(1));
+        assert_eq!(tracker.adjustment(ExchangeFeed::Kraken), 1.0);
+    }
+}
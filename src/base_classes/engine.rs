@@ -0,0 +1,577 @@
+//! The multi-venue state engine: a per-exchange view of top-of-book,
+//! trades, and ticker updates, kept consistent by [`FeedTimestampGate`] as
+//! collectors feed it events from each enabled venue.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::base_classes::demean::{DemeanTracker, DEFAULT_DEMEAN_HALF_LIFE};
+use crate::base_classes::feed_gate::{ExchangeFeed, FeedKind, FeedTimestampGate, GateDecision};
+use crate::base_classes::shutdown::{ShutdownGroup, ShutdownSignal};
+use crate::models::Side;
+
+/// How often a feed worker wakes up to check whether shutdown was signaled.
+const WORKER_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// How often the main loop's heartbeat fires by default when
+/// `EngineConfig::heartbeat_interval` isn't overridden. Bounds how long the
+/// loop can go without checking shutdown/heartbeat health even when no feed
+/// is producing data.
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A top-of-book snapshot from one venue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bbo {
+    pub bid: f64,
+    pub ask: f64,
+    pub ts_ns: i64,
+}
+
+/// A single executed trade observed on one venue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeUpdate {
+    pub price: f64,
+    pub size: f64,
+    pub side: Side,
+    pub ts_ns: i64,
+}
+
+/// A venue's last-traded-price ticker update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickerUpdate {
+    pub last_price: f64,
+    pub ts_ns: i64,
+}
+
+/// The venue-agnostic shape every collector's `events_for` produces, so
+/// `spawn_state_engine` can dispatch them into `GlobalState` the same way
+/// regardless of which exchange they came from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarketEvent {
+    Bbo(Bbo),
+    Trade(TradeUpdate),
+    Ticker(TickerUpdate),
+}
+
+/// The cross-venue market-data view collectors write into and strategies
+/// read from.
+#[derive(Debug, Default)]
+pub struct GlobalState {
+    bbo: HashMap<ExchangeFeed, Bbo>,
+    trades: HashMap<ExchangeFeed, Vec<TradeUpdate>>,
+    tickers: HashMap<ExchangeFeed, TickerUpdate>,
+}
+
+impl GlobalState {
+    pub fn new() -> Self {
+        GlobalState::default()
+    }
+
+    pub fn bbo(&self, feed: ExchangeFeed) -> Option<&Bbo> {
+        self.bbo.get(&feed)
+    }
+
+    pub fn trades(&self, feed: ExchangeFeed) -> &[TradeUpdate] {
+        self.trades.get(&feed).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn ticker(&self, feed: ExchangeFeed) -> Option<&TickerUpdate> {
+        self.tickers.get(&feed)
+    }
+}
+
+/// Applies a BBO update from `feed` to `state` if it passes `gate`'s
+/// monotonicity check.
+pub fn update_bbo_store(state: &mut GlobalState, gate: &mut FeedTimestampGate, feed: ExchangeFeed, bbo: Bbo) -> GateDecision {
+    let decision = gate.evaluate(feed, FeedKind::Bbo, bbo.ts_ns);
+    if decision == GateDecision::Accept {
+        state.bbo.insert(feed, bbo);
+    }
+    decision
+}
+
+/// Appends a trade update from `feed` to `state` if it passes `gate`'s
+/// monotonicity check.
+pub fn update_trades(state: &mut GlobalState, gate: &mut FeedTimestampGate, feed: ExchangeFeed, trade: TradeUpdate) -> GateDecision {
+    let decision = gate.evaluate(feed, FeedKind::Trade, trade.ts_ns);
+    if decision == GateDecision::Accept {
+        state.trades.entry(feed).or_default().push(trade);
+    }
+    decision
+}
+
+/// Applies a ticker update from `feed` to `state` if it passes `gate`'s
+/// monotonicity check.
+pub fn update_tickers(state: &mut GlobalState, gate: &mut FeedTimestampGate, feed: ExchangeFeed, ticker: TickerUpdate) -> GateDecision {
+    let decision = gate.evaluate(feed, FeedKind::Ticker, ticker.ts_ns);
+    if decision == GateDecision::Accept {
+        state.tickers.insert(feed, ticker);
+    }
+    decision
+}
+
+/// Which venues `spawn_state_engine` should bring up.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FeedToggles {
+    pub binance: bool,
+    pub bybit: bool,
+    pub gate: bool,
+    pub okx: bool,
+    pub bitget: bool,
+    pub kraken: bool,
+    pub coinbase: bool,
+}
+
+/// Runtime-mutable per-feed enable flags, checked by each market-data feed
+/// worker on every poll tick, so an operator can pause or resume processing
+/// for one venue live without restarting the engine. Unlike `FeedToggles`,
+/// which only decides which workers `spawn_state_engine` starts, this can be
+/// flipped after the engine is already running; it doesn't spawn or join
+/// threads itself, so disabling a feed pauses its already-running worker
+/// rather than tearing it down.
+#[derive(Debug)]
+pub struct RuntimeFeedToggles {
+    flags: HashMap<ExchangeFeed, AtomicBool>,
+}
+
+impl RuntimeFeedToggles {
+    pub fn new(initial: FeedToggles) -> Self {
+        let mut flags = HashMap::new();
+        flags.insert(ExchangeFeed::Binance, AtomicBool::new(initial.binance));
+        flags.insert(ExchangeFeed::Bybit, AtomicBool::new(initial.bybit));
+        flags.insert(ExchangeFeed::Gate, AtomicBool::new(initial.gate));
+        flags.insert(ExchangeFeed::Okx, AtomicBool::new(initial.okx));
+        flags.insert(ExchangeFeed::Bitget, AtomicBool::new(initial.bitget));
+        flags.insert(ExchangeFeed::Kraken, AtomicBool::new(initial.kraken));
+        flags.insert(ExchangeFeed::Coinbase, AtomicBool::new(initial.coinbase));
+        RuntimeFeedToggles { flags }
+    }
+
+    pub fn set(&self, feed: ExchangeFeed, enabled: bool) {
+        if let Some(flag) = self.flags.get(&feed) {
+            flag.store(enabled, Ordering::Release);
+        }
+    }
+
+    pub fn is_enabled(&self, feed: ExchangeFeed) -> bool {
+        self.flags
+            .get(&feed)
+            .map(|flag| flag.load(Ordering::Acquire))
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EngineConfig {
+    pub feeds: FeedToggles,
+    /// The `limit` passed to Binance's REST depth snapshot endpoint when
+    /// seeding `binance_book`. Must be one of
+    /// [`crate::exchanges::binance::ALLOWED_SNAPSHOT_DEPTHS`].
+    pub binance_snapshot_depth: u32,
+    /// The half-life [`EngineHandle`]'s [`DemeanTracker`] decays each
+    /// venue's cross-venue price offset over. Shorter suits fast markets
+    /// where the basis shifts quickly; longer suits slow ones. Defaults to
+    /// [`DEFAULT_DEMEAN_HALF_LIFE`].
+    pub demean_half_life: Duration,
+    /// How long the main loop's bounded wait can go with no feed progress
+    /// before it wakes anyway to check shutdown/heartbeat health. Defaults
+    /// to [`DEFAULT_HEARTBEAT_INTERVAL`].
+    pub heartbeat_interval: Duration,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        EngineConfig {
+            feeds: FeedToggles::default(),
+            binance_snapshot_depth: 1000,
+            demean_half_life: DEFAULT_DEMEAN_HALF_LIFE,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Builds a config, rejecting a `binance_snapshot_depth` Binance's REST
+    /// endpoint wouldn't accept.
+    pub fn new(feeds: FeedToggles, binance_snapshot_depth: u32) -> Result<Self, String> {
+        crate::exchanges::binance::validate_snapshot_depth(binance_snapshot_depth)?;
+        Ok(EngineConfig {
+            feeds,
+            binance_snapshot_depth,
+            demean_half_life: DEFAULT_DEMEAN_HALF_LIFE,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+        })
+    }
+
+    /// Overrides the demean half-life used for cross-venue price alignment.
+    pub fn configure_demean(mut self, half_life: Duration) -> Self {
+        self.demean_half_life = half_life;
+        self
+    }
+
+    /// Overrides how long the main loop's bounded wait can go with no feed
+    /// progress before it wakes anyway.
+    pub fn configure_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+}
+
+/// The shared state and gate produced by `spawn_state_engine`, held by the
+/// caller for as long as the engine's feeds should keep running.
+pub struct EngineHandle {
+    state: Arc<Mutex<GlobalState>>,
+    gate: Arc<Mutex<FeedTimestampGate>>,
+    demean: Arc<Mutex<DemeanTracker>>,
+    /// The Binance book seeded at `config.binance_snapshot_depth`, present
+    /// only when `config.feeds.binance` is enabled.
+    pub binance_book: Option<crate::exchanges::binance::BinanceBook>,
+    shutdown: ShutdownGroup,
+    main_loop: StateEngineHandle,
+    toggles: Arc<RuntimeFeedToggles>,
+    /// Poll-tick counters for every feed worker `spawn_state_engine` started,
+    /// for tests and diagnostics to observe a runtime toggle take effect.
+    processed: HashMap<ExchangeFeed, Arc<AtomicU64>>,
+    heartbeat_count: Arc<AtomicU64>,
+}
+
+impl EngineHandle {
+    pub fn lock_state(&self) -> MutexGuard<'_, GlobalState> {
+        self.state.lock().unwrap()
+    }
+
+    pub fn lock_gate(&self) -> MutexGuard<'_, FeedTimestampGate> {
+        self.gate.lock().unwrap()
+    }
+
+    pub fn lock_demean(&self) -> MutexGuard<'_, DemeanTracker> {
+        self.demean.lock().unwrap()
+    }
+
+    pub fn state_handle(&self) -> Arc<Mutex<GlobalState>> {
+        self.state.clone()
+    }
+
+    pub fn gate_handle(&self) -> Arc<Mutex<FeedTimestampGate>> {
+        self.gate.clone()
+    }
+
+    /// How many helper threads `spawn_state_engine` started and is tracking
+    /// for shutdown.
+    pub fn worker_count(&self) -> usize {
+        self.shutdown.tracked_count()
+    }
+
+    /// Broadcasts shutdown to every spawned feed worker, the user-trades
+    /// listener, and the main engine loop, and joins them all before
+    /// returning.
+    pub fn shutdown(self) {
+        self.shutdown.shutdown();
+        let _ = self.main_loop.join.join();
+    }
+
+    /// Disables or re-enables `feed`'s already-running worker live. A no-op
+    /// for a feed that wasn't enabled (and so never spawned) at construction.
+    pub fn set_feed_enabled(&self, feed: ExchangeFeed, enabled: bool) {
+        self.toggles.set(feed, enabled);
+    }
+
+    /// How many poll ticks `feed`'s worker has processed while enabled.
+    /// Zero for a feed that was never enabled at construction.
+    pub fn processed_count(&self, feed: ExchangeFeed) -> u64 {
+        self.processed
+            .get(&feed)
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    /// How many times the main loop's bounded wait has woken to check
+    /// shutdown/heartbeat health, including wakes with no feed progress.
+    pub fn heartbeat_count(&self) -> u64 {
+        self.heartbeat_count.load(Ordering::Relaxed)
+    }
+}
+
+/// The main engine loop's own join handle and shutdown flag, separate from
+/// the per-feed collector workers `EngineHandle` tracks, for a caller that
+/// only needs to stop/await the engine's own housekeeping loop (previously
+/// an unstoppable `loop {}`).
+pub struct StateEngineHandle {
+    pub join: thread::JoinHandle<()>,
+    pub shutdown: Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// Spawns the engine's main loop, which checks `signal` at the top of every
+/// iteration and returns as soon as it's set. It holds no reference to
+/// `GlobalState` and does no housekeeping over it. The loop
+/// never blocks longer than `WORKER_POLL_INTERVAL` at a time, so it notices
+/// shutdown promptly; every time `heartbeat_interval` has elapsed since the
+/// last one, it also bumps `heartbeat_count`, bounding how long the loop can
+/// go without a heartbeat even when every feed is quiet.
+fn spawn_main_loop(signal: ShutdownSignal, heartbeat_interval: Duration, heartbeat_count: Arc<AtomicU64>) -> StateEngineHandle {
+    let shutdown = signal.as_atomic();
+    let join = thread::spawn(move || {
+        let mut last_heartbeat = Instant::now();
+        while !signal.is_signaled() {
+            thread::sleep(WORKER_POLL_INTERVAL);
+            if last_heartbeat.elapsed() >= heartbeat_interval {
+                heartbeat_count.fetch_add(1, Ordering::Relaxed);
+                last_heartbeat = Instant::now();
+            }
+        }
+    });
+    StateEngineHandle { join, shutdown }
+}
+
+/// Spawns a worker thread for one enabled feed that polls `signal` until
+/// shutdown is broadcast, then exits. The actual websocket connection and
+/// frame dispatch a real feed worker would run is out of scope here; this
+/// models the poll-and-exit lifecycle every worker must honor.
+fn spawn_feed_worker(signal: ShutdownSignal) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        while !signal.is_signaled() {
+            thread::sleep(WORKER_POLL_INTERVAL);
+        }
+    })
+}
+
+/// Spawns a worker thread for one market-data feed whose processing can be
+/// paused and resumed live via `toggles`, without restarting the engine.
+/// Bumps `processed` once per poll tick while `feed` is enabled; this models
+/// "processing frames" the same way `spawn_feed_worker`'s poll-and-exit loop
+/// models a worker's lifecycle, since neither has a real frame source yet.
+fn spawn_toggleable_feed_worker(
+    signal: ShutdownSignal,
+    toggles: Arc<RuntimeFeedToggles>,
+    feed: ExchangeFeed,
+    processed: Arc<AtomicU64>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        while !signal.is_signaled() {
+            if toggles.is_enabled(feed) {
+                processed.fetch_add(1, Ordering::Relaxed);
+            }
+            thread::sleep(WORKER_POLL_INTERVAL);
+        }
+    })
+}
+
+/// Spawns the listener that watches the user-trades/fills websocket and
+/// exits once `signal` is broadcast, so `spawn_state_engine`'s shutdown can
+/// join it alongside the per-feed market-data workers.
+pub fn spawn_gate_user_trades_listener(signal: ShutdownSignal) -> thread::JoinHandle<()> {
+    spawn_feed_worker(signal)
+}
+
+/// Brings up the shared `GlobalState`/`FeedTimestampGate` pair every enabled
+/// venue's collector writes into, and spawns one worker thread per enabled
+/// feed plus the user-trades listener, all tracked under a shared
+/// `ShutdownSignal`. Decoding real websocket frames into collector calls is
+/// the caller's responsibility; `EngineHandle::shutdown` brings every
+/// spawned helper down and joins it instead of leaving it detached.
+pub fn spawn_state_engine(config: &EngineConfig) -> EngineHandle {
+    let binance_book = if config.feeds.binance {
+        let mut book = crate::exchanges::binance::BinanceBook::new();
+        // `EngineConfig::new` validates this depth and `EngineConfig::default`
+        // uses 1000, an allowed one. A depth written straight into the public
+        // field is not checked again, and an invalid one panics here.
+        book.init_from_rest(config.binance_snapshot_depth)
+            .expect("EngineConfig validates binance_snapshot_depth on construction");
+        Some(book)
+    } else {
+        None
+    };
+
+    let mut shutdown = ShutdownGroup::new();
+    let toggles = Arc::new(RuntimeFeedToggles::new(config.feeds));
+    let mut processed = HashMap::new();
+    let enabled_feeds = [
+        (ExchangeFeed::Binance, config.feeds.binance),
+        (ExchangeFeed::Bybit, config.feeds.bybit),
+        (ExchangeFeed::Gate, config.feeds.gate),
+        (ExchangeFeed::Okx, config.feeds.okx),
+        (ExchangeFeed::Bitget, config.feeds.bitget),
+        (ExchangeFeed::Kraken, config.feeds.kraken),
+        (ExchangeFeed::Coinbase, config.feeds.coinbase),
+    ];
+    for (feed, enabled) in enabled_feeds {
+        if enabled {
+            let counter = Arc::new(AtomicU64::new(0));
+            let handle = spawn_toggleable_feed_worker(shutdown.signal(), toggles.clone(), feed, counter.clone());
+            shutdown.track(handle);
+            processed.insert(feed, counter);
+        }
+    }
+    shutdown.track(spawn_gate_user_trades_listener(shutdown.signal()));
+    let heartbeat_count = Arc::new(AtomicU64::new(0));
+    let main_loop = spawn_main_loop(shutdown.signal(), config.heartbeat_interval, heartbeat_count.clone());
+
+    EngineHandle {
+        state: Arc::new(Mutex::new(GlobalState::new())),
+        gate: Arc::new(Mutex::new(FeedTimestampGate::new())),
+        demean: Arc::new(Mutex::new(DemeanTracker::new(config.demean_half_life))),
+        binance_book,
+        shutdown,
+        main_loop,
+        toggles,
+        processed,
+        heartbeat_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn engine_config_rejects_an_invalid_binance_snapshot_depth() {
+        assert!(EngineConfig::new(FeedToggles::default(), 123).is_err());
+    }
+
+    #[test]
+    fn spawn_state_engine_seeds_the_binance_book_at_the_configured_depth() {
+        let config = EngineConfig::new(FeedToggles { binance: true, ..Default::default() }, 20).unwrap();
+        let handle = spawn_state_engine(&config);
+        assert_eq!(handle.binance_book.unwrap().depth, 20);
+    }
+
+    #[test]
+    fn spawn_state_engine_skips_the_binance_book_when_the_feed_is_disabled() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000).unwrap();
+        let handle = spawn_state_engine(&config);
+        assert!(handle.binance_book.is_none());
+    }
+
+    #[test]
+    fn engine_stops_within_a_timeout_when_every_feed_is_disabled() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000).unwrap();
+        let handle = spawn_state_engine(&config);
+
+        let start = std::time::Instant::now();
+        handle.shutdown();
+        assert!(start.elapsed() < std::time::Duration::from_secs(2), "engine main loop did not stop in time");
+    }
+
+    #[test]
+    fn shutdown_joins_every_spawned_feed_worker_within_a_timeout() {
+        let config = EngineConfig::new(
+            FeedToggles { kraken: true, coinbase: true, ..Default::default() },
+            1000,
+        )
+        .unwrap();
+        let handle = spawn_state_engine(&config);
+        // Two market-data feed workers (kraken, coinbase) plus the
+        // user-trades listener.
+        assert_eq!(handle.worker_count(), 3);
+
+        let start = std::time::Instant::now();
+        handle.shutdown();
+        assert!(start.elapsed() < std::time::Duration::from_secs(2), "shutdown took too long to join workers");
+    }
+
+    #[test]
+    fn stale_bbo_update_is_rejected_and_does_not_overwrite_state() {
+        let mut state = GlobalState::new();
+        let mut gate = FeedTimestampGate::new();
+
+        let decision = update_bbo_store(
+            &mut state,
+            &mut gate,
+            ExchangeFeed::Kraken,
+            Bbo { bid: 100.0, ask: 100.5, ts_ns: 1000 },
+        );
+        assert_eq!(decision, GateDecision::Accept);
+
+        let decision = update_bbo_store(
+            &mut state,
+            &mut gate,
+            ExchangeFeed::Kraken,
+            Bbo { bid: 90.0, ask: 90.5, ts_ns: 500 },
+        );
+        assert_eq!(decision, GateDecision::Reject);
+        assert_eq!(state.bbo(ExchangeFeed::Kraken).unwrap().bid, 100.0);
+    }
+
+    #[test]
+    fn engine_config_defaults_to_the_standard_demean_half_life() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000).unwrap();
+        assert_eq!(config.demean_half_life, DEFAULT_DEMEAN_HALF_LIFE);
+    }
+
+    #[test]
+    fn configure_demean_overrides_the_half_life_the_engine_seeds_its_tracker_with() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000)
+            .unwrap()
+            .configure_demean(Duration::from_secs(30));
+        let handle = spawn_state_engine(&config);
+        assert_eq!(handle.lock_demean().half_life(), Duration::from_secs(30));
+        handle.shutdown();
+    }
+
+    #[test]
+    fn engine_config_defaults_to_the_standard_heartbeat_interval() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000).unwrap();
+        assert_eq!(config.heartbeat_interval, DEFAULT_HEARTBEAT_INTERVAL);
+    }
+
+    #[test]
+    fn main_loop_wakes_on_the_configured_heartbeat_even_with_no_feeds_enabled() {
+        let config = EngineConfig::new(FeedToggles::default(), 1000)
+            .unwrap()
+            .configure_heartbeat_interval(Duration::from_millis(10));
+        let handle = spawn_state_engine(&config);
+
+        wait_until(|| handle.heartbeat_count() >= 2);
+
+        handle.shutdown();
+    }
+
+    #[test]
+    fn disabling_a_feed_live_pauses_its_worker_and_re_enabling_resumes_it() {
+        let config = EngineConfig::new(FeedToggles { kraken: true, ..Default::default() }, 1000).unwrap();
+        let handle = spawn_state_engine(&config);
+
+        wait_until(|| handle.processed_count(ExchangeFeed::Kraken) > 0);
+
+        handle.set_feed_enabled(ExchangeFeed::Kraken, false);
+        let paused_at = handle.processed_count(ExchangeFeed::Kraken);
+        thread::sleep(Duration::from_millis(30));
+        assert_eq!(
+            handle.processed_count(ExchangeFeed::Kraken),
+            paused_at,
+            "a disabled feed's worker should stop advancing its processed count"
+        );
+
+        handle.set_feed_enabled(ExchangeFeed::Kraken, true);
+        wait_until(|| handle.processed_count(ExchangeFeed::Kraken) > paused_at);
+
+        handle.shutdown();
+    }
+
+    /// Spins until `condition` is true or 2 seconds pass, for assertions on
+    /// state a background worker thread updates asynchronously.
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let start = std::time::Instant::now();
+        while !condition() {
+            assert!(start.elapsed() < Duration::from_secs(2), "condition did not become true in time");
+            thread::sleep(Duration::from_millis(2));
+        }
+    }
+
+    #[test]
+    fn feeds_are_tracked_independently_in_global_state() {
+        let mut state = GlobalState::new();
+        let mut gate = FeedTimestampGate::new();
+        update_trades(
+            &mut state,
+            &mut gate,
+            ExchangeFeed::Kraken,
+            TradeUpdate { price: 100.0, size: 1.0, side: Side::Buy, ts_ns: 100 },
+        );
+        assert_eq!(state.trades(ExchangeFeed::Kraken).len(), 1);
+        assert_eq!(state.trades(ExchangeFeed::Binance).len(), 0);
+    }
+}
@@ -0,0 +1,359 @@
+//! Enforces per-venue timestamp monotonicity before an update is allowed
+//! into `GlobalState`, so a replayed or out-of-order frame can't corrupt the
+//! cross-venue view.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The exchanges the engine can ingest market data from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExchangeFeed {
+    Binance,
+    Bybit,
+    Gate,
+    Okx,
+    Bitget,
+    Kraken,
+    Coinbase,
+}
+
+impl ExchangeFeed {
+    /// Every feed the engine knows about, for code that needs to iterate
+    /// all venues (e.g. polling `GlobalState` for each of them).
+    pub const ALL: [ExchangeFeed; 7] = [
+        ExchangeFeed::Binance,
+        ExchangeFeed::Bybit,
+        ExchangeFeed::Gate,
+        ExchangeFeed::Okx,
+        ExchangeFeed::Bitget,
+        ExchangeFeed::Kraken,
+        ExchangeFeed::Coinbase,
+    ];
+
+    /// A stable lowercase name for this feed, for use as a serialized key
+    /// (e.g. the dashboard's JSON state export) instead of `Debug`'s
+    /// capitalized variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExchangeFeed::Binance => "binance",
+            ExchangeFeed::Bybit => "bybit",
+            ExchangeFeed::Gate => "gate",
+            ExchangeFeed::Okx => "okx",
+            ExchangeFeed::Bitget => "bitget",
+            ExchangeFeed::Kraken => "kraken",
+            ExchangeFeed::Coinbase => "coinbase",
+        }
+    }
+}
+
+/// The kind of update a feed carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedKind {
+    Bbo,
+    Trade,
+    Ticker,
+}
+
+/// The outcome of [`FeedTimestampGate::evaluate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateDecision {
+    /// The update is no further behind the highest accepted timestamp than
+    /// the gate's backstep tolerance allows (zero by default), and should
+    /// be applied.
+    Accept,
+    /// The update is stale and should be dropped.
+    Reject,
+}
+
+/// How stale a feed has been: how many of its updates were accepted versus
+/// rejected, and the worst `received_at - ts` gap seen, so a dashboard can
+/// surface which venue is lagging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeedMetric {
+    pub exchange: ExchangeFeed,
+    pub kind: FeedKind,
+    pub accepted: u64,
+    pub rejected: u64,
+    pub max_latency_ns: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct FeedAccounting {
+    accepted: u64,
+    rejected: u64,
+    max_latency_ns: i64,
+}
+
+/// Tracks, per `(ExchangeFeed, FeedKind)`, the highest accepted exchange
+/// timestamp and rejects anything older than `allowed_backstep_ns` behind
+/// it.
+pub struct FeedTimestampGate {
+    last_ts_ns: HashMap<(ExchangeFeed, FeedKind), i64>,
+    /// Per-exchange clock-offset estimate (`received_at - source_system_ts_ns`),
+    /// added to the incoming timestamp before the monotonicity check
+    /// so a venue with a slightly-behind clock isn't unfairly rejected.
+    clock_offset_ns: HashMap<ExchangeFeed, i64>,
+    accounting: HashMap<(ExchangeFeed, FeedKind), FeedAccounting>,
+    /// How far behind the highest accepted timestamp an update may still be
+    /// and get accepted. Zero (the default) means strict monotonicity.
+    allowed_backstep_ns: i64,
+}
+
+impl Default for FeedTimestampGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedTimestampGate {
+    /// Builds a gate requiring strict monotonicity (zero backstep
+    /// tolerance), the original behavior.
+    pub fn new() -> Self {
+        FeedTimestampGate {
+            last_ts_ns: HashMap::new(),
+            clock_offset_ns: HashMap::new(),
+            accounting: HashMap::new(),
+            allowed_backstep_ns: 0,
+        }
+    }
+
+    /// Builds a gate that accepts updates up to `tolerance` older than the
+    /// highest accepted timestamp for a `(exchange, kind)`, instead of
+    /// rejecting any backstep. Updates beyond the tolerance still reject via
+    /// [`GateDecision::Reject`].
+    pub fn with_tolerance(tolerance: Duration) -> Self {
+        FeedTimestampGate {
+            allowed_backstep_ns: tolerance.as_nanos() as i64,
+            ..FeedTimestampGate::new()
+        }
+    }
+
+    /// Sets the clock-offset correction applied to timestamps from `exchange`
+    /// before the monotonicity check. A positive offset means the venue's
+    /// clock runs behind ours by that many nanoseconds.
+    pub fn set_clock_offset(&mut self, exchange: ExchangeFeed, offset_ns: i64) {
+        self.clock_offset_ns.insert(exchange, offset_ns);
+    }
+
+    /// Returns the currently configured clock offset for `exchange`, if any.
+    pub fn clock_offset(&self, exchange: ExchangeFeed) -> i64 {
+        *self.clock_offset_ns.get(&exchange).unwrap_or(&0)
+    }
+
+    /// Evaluates whether an update with exchange timestamp `ts_ns` for
+    /// `(exchange, kind)` should be accepted, applying the clock-offset
+    /// correction before comparing against the last accepted timestamp.
+    pub fn evaluate(&mut self, exchange: ExchangeFeed, kind: FeedKind, ts_ns: i64) -> GateDecision {
+        self.evaluate_at(exchange, kind, ts_ns, now_ns())
+    }
+
+    /// Same as [`FeedTimestampGate::evaluate`], but with the "received at"
+    /// timestamp passed in explicitly instead of read from the system clock,
+    /// so latency accounting is deterministic in tests.
+    pub fn evaluate_at(&mut self, exchange: ExchangeFeed, kind: FeedKind, ts_ns: i64, received_at_ns: i64) -> GateDecision {
+        let corrected_ts_ns = ts_ns + self.clock_offset(exchange);
+        let key = (exchange, kind);
+        let decision = match self.last_ts_ns.get(&key) {
+            Some(&last) if corrected_ts_ns < last - self.allowed_backstep_ns => GateDecision::Reject,
+            Some(&last) => {
+                self.last_ts_ns.insert(key, last.max(corrected_ts_ns));
+                GateDecision::Accept
+            }
+            None => {
+                self.last_ts_ns.insert(key, corrected_ts_ns);
+                GateDecision::Accept
+            }
+        };
+
+        let accounting = self.accounting.entry(key).or_default();
+        match decision {
+            GateDecision::Accept => accounting.accepted += 1,
+            GateDecision::Reject => accounting.rejected += 1,
+        }
+        let latency_ns = received_at_ns - ts_ns;
+        accounting.max_latency_ns = accounting.max_latency_ns.max(latency_ns);
+
+        decision
+    }
+
+    /// A point-in-time snapshot of accepted/rejected counts and worst
+    /// observed latency for every `(ExchangeFeed, FeedKind)` this gate has
+    /// evaluated an update for.
+    pub fn snapshot_metrics(&self) -> Vec<FeedMetric> {
+        self.accounting
+            .iter()
+            .map(|(&(exchange, kind), accounting)| FeedMetric {
+                exchange,
+                kind,
+                accepted: accounting.accepted,
+                rejected: accounting.rejected,
+                max_latency_ns: accounting.max_latency_ns,
+            })
+            .collect()
+    }
+}
+
+fn now_ns() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_nanos() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_out_of_order_updates_without_offset() {
+        let mut gate = FeedTimestampGate::new();
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 1000),
+            GateDecision::Accept
+        );
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 900),
+            GateDecision::Reject
+        );
+    }
+
+    #[test]
+    fn clock_offset_rescues_updates_that_would_otherwise_be_rejected() {
+        let mut gate = FeedTimestampGate::new();
+        gate.evaluate(ExchangeFeed::Gate, FeedKind::Bbo, 1000);
+
+        // Without correction, an update stamped 950 is older than the 1000
+        // already accepted and is rejected.
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Gate, FeedKind::Bbo, 950),
+            GateDecision::Reject
+        );
+        // Gate's clock runs 200ns behind ours: corrected, ts=960 becomes
+        // 1160, fresher than 1000, so it is accepted.
+        gate.set_clock_offset(ExchangeFeed::Gate, 200);
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Gate, FeedKind::Bbo, 960),
+            GateDecision::Accept
+        );
+
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Gate, FeedKind::Bbo, 970),
+            GateDecision::Accept
+        );
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Gate, FeedKind::Bbo, 950),
+            GateDecision::Reject
+        );
+    }
+
+    #[test]
+    fn snapshot_metrics_counts_accepted_and_rejected_updates_per_feed_and_kind() {
+        let mut gate = FeedTimestampGate::new();
+
+        // In order: both accepted.
+        gate.evaluate_at(ExchangeFeed::Binance, FeedKind::Bbo, 1000, 1050);
+        gate.evaluate_at(ExchangeFeed::Binance, FeedKind::Bbo, 1100, 1120);
+        // Out of order: rejected.
+        gate.evaluate_at(ExchangeFeed::Binance, FeedKind::Bbo, 1050, 1200);
+
+        let metrics = gate.snapshot_metrics();
+        let binance_bbo = metrics
+            .iter()
+            .find(|m| m.exchange == ExchangeFeed::Binance && m.kind == FeedKind::Bbo)
+            .unwrap();
+        assert_eq!(binance_bbo.accepted, 2);
+        assert_eq!(binance_bbo.rejected, 1);
+        // Worst gap is the rejected update: received at 1200, timestamped 1050.
+        assert_eq!(binance_bbo.max_latency_ns, 150);
+    }
+
+    #[test]
+    fn snapshot_metrics_tracks_every_feed_and_kind_independently() {
+        let mut gate = FeedTimestampGate::new();
+        gate.evaluate_at(ExchangeFeed::Binance, FeedKind::Trade, 100, 110);
+        gate.evaluate_at(ExchangeFeed::Kraken, FeedKind::Bbo, 200, 260);
+
+        let metrics = gate.snapshot_metrics();
+        assert_eq!(metrics.len(), 2);
+
+        let binance_trade = metrics
+            .iter()
+            .find(|m| m.exchange == ExchangeFeed::Binance && m.kind == FeedKind::Trade)
+            .unwrap();
+        assert_eq!(binance_trade.accepted, 1);
+        assert_eq!(binance_trade.max_latency_ns, 10);
+
+        let kraken_bbo = metrics
+            .iter()
+            .find(|m| m.exchange == ExchangeFeed::Kraken && m.kind == FeedKind::Bbo)
+            .unwrap();
+        assert_eq!(kraken_bbo.accepted, 1);
+        assert_eq!(kraken_bbo.max_latency_ns, 60);
+    }
+
+    #[test]
+    fn default_gate_has_zero_tolerance_and_rejects_any_backstep() {
+        let mut gate = FeedTimestampGate::new();
+        gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 1000);
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 999),
+            GateDecision::Reject
+        );
+    }
+
+    #[test]
+    fn with_tolerance_accepts_backsteps_within_the_configured_tolerance() {
+        let mut gate = FeedTimestampGate::with_tolerance(Duration::from_nanos(100));
+        gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 1000);
+
+        // 100ns behind the high-water mark: exactly at the tolerance, accepted.
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 900),
+            GateDecision::Accept
+        );
+        // 150ns behind the high-water mark (still 1000, since accepted
+        // backsteps don't regress it): beyond tolerance, rejected.
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 850),
+            GateDecision::Reject
+        );
+    }
+
+    #[test]
+    fn accepted_backsteps_do_not_regress_the_high_water_mark() {
+        let mut gate = FeedTimestampGate::with_tolerance(Duration::from_nanos(200));
+        gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 1000);
+        gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 900);
+
+        // Still measured against the 1000 high-water mark, not the 900 that
+        // was just accepted, so tolerance can't be chained to drift further back.
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 799),
+            GateDecision::Reject
+        );
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 820),
+            GateDecision::Accept
+        );
+    }
+
+    #[test]
+    fn as_str_gives_a_stable_lowercase_name_per_feed() {
+        assert_eq!(ExchangeFeed::Kraken.as_str(), "kraken");
+        assert_eq!(ExchangeFeed::Coinbase.as_str(), "coinbase");
+    }
+
+    #[test]
+    fn feeds_and_kinds_are_tracked_independently() {
+        let mut gate = FeedTimestampGate::new();
+        gate.evaluate(ExchangeFeed::Binance, FeedKind::Bbo, 500);
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Binance, FeedKind::Trade, 100),
+            GateDecision::Accept
+        );
+        assert_eq!(
+            gate.evaluate(ExchangeFeed::Bybit, FeedKind::Bbo, 100),
+            GateDecision::Accept
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod demean;
+pub mod engine;
+pub mod feed_gate;
+pub mod parse_diagnostics;
+pub mod replay_feed;
+pub mod ring_buffer;
+pub mod shutdown;
+pub mod state;
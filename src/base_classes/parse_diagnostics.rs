@@ -0,0 +1,41 @@
+//! A tiny counted-diagnostics accumulator for collector parsers. Extra,
+//! unrecognized fields are tolerated silently (serde already ignores them
+//! by default), but a parse that fails because a required field is missing
+//! should be counted rather than just discarded by the caller.
+
+/// Running counts of successful versus failed parse attempts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseDiagnostics {
+    pub parsed: u64,
+    pub errors: u64,
+}
+
+impl ParseDiagnostics {
+    pub fn new() -> Self {
+        ParseDiagnostics::default()
+    }
+
+    /// Records the outcome of one parse attempt.
+    pub fn record<T, E>(&mut self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.parsed += 1,
+            Err(_) => self.errors += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_successes_and_failures_independently() {
+        let mut diagnostics = ParseDiagnostics::new();
+        diagnostics.record::<(), &str>(&Ok(()));
+        diagnostics.record::<(), &str>(&Ok(()));
+        diagnostics.record::<(), &str>(&Err("missing field"));
+
+        assert_eq!(diagnostics.parsed, 2);
+        assert_eq!(diagnostics.errors, 1);
+    }
+}
@@ -0,0 +1,253 @@
+//! A fixed-capacity single-producer/single-consumer ring buffer used by the
+//! WS worker threads to hand raw frames to the state engine.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// How a [`Producer`] behaves when the consumer hasn't kept up and the
+/// buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Overwrite the oldest unconsumed frame and bump the drop counter.
+    /// Appropriate for feeds where the latest value matters more than
+    /// every intermediate one (e.g. best-bid/ask snapshots).
+    Overwrite,
+    /// Block the producer (briefly yielding) until the consumer frees a
+    /// slot, so no frame is ever lost. Appropriate for correctness-critical
+    /// feeds such as orderbook deltas where dropping breaks the sequence.
+    Backpressure,
+    /// Drop the incoming frame and bump the drop counter, leaving every
+    /// unconsumed slot untouched. Appropriate when older, already-buffered
+    /// frames are more valuable than whatever just arrived.
+    RejectNewest,
+}
+
+/// The mutable queue state, touched by both sides under [`Inner::queue`].
+/// A `VecDeque` keeps the bookkeeping for "oldest"/"newest" and wraparound
+/// trivial and obviously correct; the mutex is held only for the handful of
+/// instructions it takes to push or pop, so contention is a non-issue next
+/// to the network I/O these frames came from.
+struct Queue<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+struct Inner<T> {
+    queue: Mutex<Queue<T>>,
+    dropped: AtomicU64,
+    policy: OverflowPolicy,
+}
+
+/// The producer's write-side view of a buffer made by [`ring_buffer`] or
+/// [`ring_buffer_with_policy`].
+pub struct Producer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// The consumer's read-side view of a buffer made by [`ring_buffer`] or
+/// [`ring_buffer_with_policy`].
+pub struct Consumer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// Creates a linked producer/consumer pair backed by a ring buffer with the
+/// given capacity, overwriting the oldest frame on overflow.
+pub fn ring_buffer<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    ring_buffer_with_policy(capacity, OverflowPolicy::Overwrite)
+}
+
+/// Creates a linked producer/consumer pair with an explicit [`OverflowPolicy`].
+pub fn ring_buffer_with_policy<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity > 0, "ring buffer capacity must be non-zero");
+    let inner = Arc::new(Inner {
+        queue: Mutex::new(Queue {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }),
+        dropped: AtomicU64::new(0),
+        policy,
+    });
+    (
+        Producer {
+            inner: inner.clone(),
+        },
+        Consumer { inner },
+    )
+}
+
+impl<T> Producer<T> {
+    /// Pushes a frame. Under [`OverflowPolicy::Overwrite`] this overwrites
+    /// the oldest unconsumed frame (and bumps the drop counter) when full;
+    /// under [`OverflowPolicy::Backpressure`] it yields until the consumer
+    /// frees a slot, so no frame is lost; under [`OverflowPolicy::RejectNewest`]
+    /// it drops `value` itself (and bumps the drop counter) instead of
+    /// touching the buffer.
+    pub fn push(&self, value: T) {
+        loop {
+            let mut queue = self.inner.queue.lock().unwrap();
+            let full = queue.items.len() == queue.capacity;
+
+            if full {
+                match self.inner.policy {
+                    OverflowPolicy::Backpressure => {
+                        drop(queue);
+                        thread::yield_now();
+                        continue;
+                    }
+                    OverflowPolicy::RejectNewest => {
+                        self.inner.dropped.fetch_add(1, Ordering::Relaxed);
+                        return;
+                    }
+                    OverflowPolicy::Overwrite => {
+                        queue.items.pop_front();
+                        self.inner.dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+
+            queue.items.push_back(value);
+            return;
+        }
+    }
+
+    /// Total number of frames dropped due to overflow since creation.
+    pub fn dropped_count(&self) -> u64 {
+        self.inner.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Consumer<T> {
+    /// Pops the oldest available frame, if any.
+    pub fn try_pop(&self) -> Option<T> {
+        self.inner.queue.lock().unwrap().items.pop_front()
+    }
+
+    /// Total number of frames dropped by the producer due to overflow.
+    pub fn dropped_count(&self) -> u64 {
+        self.inner.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushes_and_pops_in_order() {
+        let (producer, consumer) = ring_buffer::<u32>(4);
+        producer.push(1);
+        producer.push(2);
+        assert_eq!(consumer.try_pop(), Some(1));
+        assert_eq!(consumer.try_pop(), Some(2));
+        assert_eq!(consumer.try_pop(), None);
+    }
+
+    #[test]
+    fn overflow_increments_drop_counter() {
+        let (producer, consumer) = ring_buffer::<u32>(4);
+        // Capacity 4 holds at most 4 live items before wrapping overwrites.
+        for i in 0..10u32 {
+            producer.push(i);
+        }
+        assert_eq!(producer.dropped_count(), 6);
+        assert_eq!(consumer.dropped_count(), 6);
+
+        // The consumer should still be able to drain whatever survived.
+        let mut drained = Vec::new();
+        while let Some(v) = consumer.try_pop() {
+            drained.push(v);
+        }
+        assert_eq!(drained, vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn backpressure_mode_loses_no_frames_under_a_slow_consumer() {
+        let (producer, consumer) = ring_buffer_with_policy::<u32>(4, OverflowPolicy::Backpressure);
+        let total = 50u32;
+        let producer_thread = thread::spawn(move || {
+            for i in 0..total {
+                producer.push(i);
+            }
+        });
+
+        let mut drained = Vec::new();
+        while drained.len() < total as usize {
+            if let Some(v) = consumer.try_pop() {
+                drained.push(v);
+            } else {
+                thread::yield_now();
+            }
+        }
+        producer_thread.join().unwrap();
+
+        assert_eq!(drained, (0..total).collect::<Vec<_>>());
+        assert_eq!(consumer.dropped_count(), 0);
+    }
+
+    #[test]
+    fn overwrite_mode_drops_and_counts_under_a_slow_consumer() {
+        let (producer, consumer) = ring_buffer_with_policy::<u32>(4, OverflowPolicy::Overwrite);
+        for i in 0..10u32 {
+            producer.push(i);
+        }
+        assert!(consumer.dropped_count() > 0);
+    }
+
+    #[test]
+    fn overwrite_mode_under_a_real_concurrent_producer_and_consumer_never_duplicates_or_reorders() {
+        let (producer, consumer) = ring_buffer_with_policy::<u32>(4, OverflowPolicy::Overwrite);
+        let total = 20_000u32;
+        let producer_thread = thread::spawn(move || {
+            for i in 0..total {
+                producer.push(i);
+            }
+        });
+
+        let mut drained = Vec::new();
+        loop {
+            if let Some(v) = consumer.try_pop() {
+                drained.push(v);
+            } else if producer_thread.is_finished() {
+                while let Some(v) = consumer.try_pop() {
+                    drained.push(v);
+                }
+                break;
+            } else {
+                thread::yield_now();
+            }
+        }
+        producer_thread.join().unwrap();
+
+        // A lost update between the producer's overwrite-eviction and the
+        // consumer's pop racing each other would surface here as a
+        // duplicate or an out-of-order value.
+        for pair in drained.windows(2) {
+            assert!(pair[1] > pair[0], "observed out-of-order or duplicate values: {pair:?}");
+        }
+        assert_eq!(drained.len() as u64 + consumer.dropped_count(), total as u64);
+    }
+
+    #[test]
+    fn reject_newest_mode_drops_incoming_frames_and_keeps_the_oldest() {
+        let (producer, consumer) = ring_buffer_with_policy::<u32>(4, OverflowPolicy::RejectNewest);
+        // Capacity 4 holds at most 4 live items before the buffer is full.
+        for i in 0..10u32 {
+            producer.push(i);
+        }
+        assert_eq!(producer.dropped_count(), 6);
+        assert_eq!(consumer.dropped_count(), 6);
+
+        // Unlike Overwrite, the oldest frames survive; the newest ones that
+        // arrived once full were the ones dropped.
+        let mut drained = Vec::new();
+        while let Some(v) = consumer.try_pop() {
+            drained.push(v);
+        }
+        assert_eq!(drained, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,97 @@
+//! A broadcast shutdown signal every spawned feed worker thread observes,
+//! so `spawn_state_engine` can bring its helpers down cleanly on exit
+//! instead of leaking them.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// A cheaply-cloneable flag: any clone can observe a `signal()` from any
+/// other clone.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownSignal {
+    stopped: Arc<AtomicBool>,
+}
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        ShutdownSignal::default()
+    }
+
+    pub fn signal(&self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+
+    pub fn is_signaled(&self) -> bool {
+        self.stopped.load(Ordering::Acquire)
+    }
+
+    /// Exposes the underlying flag directly, for code that wants to hand a
+    /// plain `Arc<AtomicBool>` to a caller instead of this wrapper.
+    pub fn as_atomic(&self) -> Arc<AtomicBool> {
+        self.stopped.clone()
+    }
+}
+
+/// Tracks the join handles of every helper thread spawned under a shared
+/// [`ShutdownSignal`], so the engine can signal once and join all of them
+/// rather than leaving them detached.
+#[derive(Default)]
+pub struct ShutdownGroup {
+    signal: ShutdownSignal,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl ShutdownGroup {
+    pub fn new() -> Self {
+        ShutdownGroup::default()
+    }
+
+    /// A clone of this group's signal, to hand to a thread before spawning
+    /// it and tracking the resulting handle with [`ShutdownGroup::track`].
+    pub fn signal(&self) -> ShutdownSignal {
+        self.signal.clone()
+    }
+
+    pub fn track(&mut self, handle: JoinHandle<()>) {
+        self.handles.push(handle);
+    }
+
+    pub fn tracked_count(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Signals shutdown and joins every tracked thread before returning.
+    pub fn shutdown(mut self) {
+        self.signal.signal();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn shutdown_joins_every_tracked_thread_promptly() {
+        let mut group = ShutdownGroup::new();
+        for _ in 0..4 {
+            let signal = group.signal();
+            let handle = thread::spawn(move || {
+                while !signal.is_signaled() {
+                    thread::sleep(Duration::from_millis(2));
+                }
+            });
+            group.track(handle);
+        }
+        assert_eq!(group.tracked_count(), 4);
+
+        let start = Instant::now();
+        group.shutdown();
+        assert!(start.elapsed() < Duration::from_secs(2), "shutdown took too long to join helpers");
+    }
+}
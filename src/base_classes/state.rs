@@ -0,0 +1,238 @@
+//! Read-only access to the engine's [`GlobalState`] for external
+//! consumers that want to poll the latest cross-venue view without
+//! reaching into the mutex themselves.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::base_classes::engine::GlobalState;
+use crate::base_classes::feed_gate::ExchangeFeed;
+use crate::models::Side;
+
+/// A cloned-out top-of-book snapshot, safe to hold onto after the state
+/// lock is released.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BboSnapshot {
+    pub bid: f64,
+    pub ask: f64,
+    pub ts_ns: i64,
+}
+
+impl BboSnapshot {
+    /// How long ago this snapshot was current, as of `now_ns`. Saturates to
+    /// zero rather than going negative if `now_ns` is before `ts_ns`.
+    pub fn age_at(&self, now_ns: i64) -> Duration {
+        let age_ns = (now_ns - self.ts_ns).max(0);
+        Duration::from_nanos(age_ns as u64)
+    }
+}
+
+/// Locks `state` just long enough to clone out `exchange`'s latest BBO.
+pub fn latest_bbo(state: &Arc<Mutex<GlobalState>>, exchange: ExchangeFeed) -> Option<BboSnapshot> {
+    let guard = state.lock().unwrap();
+    guard.bbo(exchange).map(|bbo| BboSnapshot { bid: bbo.bid, ask: bbo.ask, ts_ns: bbo.ts_ns })
+}
+
+/// Like [`latest_bbo`], but returns `None` if the freshest snapshot is
+/// already older than `max_age` as of `now_ns`, so a strategy evaluating
+/// against it doesn't silently act on stale data across a gap in the feed.
+pub fn latest_bbo_if_fresh(
+    state: &Arc<Mutex<GlobalState>>,
+    exchange: ExchangeFeed,
+    now_ns: i64,
+    max_age: Duration,
+) -> Option<BboSnapshot> {
+    latest_bbo(state, exchange).filter(|snapshot| snapshot.age_at(now_ns) <= max_age)
+}
+
+/// Locks `state` once and returns the mid price for every venue that
+/// currently has a BBO, rather than taking the lock once per venue.
+pub fn latest_mid_all(state: &Arc<Mutex<GlobalState>>) -> Vec<(ExchangeFeed, f64)> {
+    let guard = state.lock().unwrap();
+    ExchangeFeed::ALL
+        .iter()
+        .filter_map(|&feed| guard.bbo(feed).map(|bbo| (feed, (bbo.bid + bbo.ask) / 2.0)))
+        .collect()
+}
+
+/// A serializable top-of-book snapshot for [`StateSnapshot`]. `Instant`
+/// fields aren't serializable and have none to skip here; `ts_ns` is
+/// converted to milliseconds, the unit the dashboard expects.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BboSnapshotDto {
+    pub bid: f64,
+    pub ask: f64,
+    pub ts_ms: i64,
+}
+
+/// A serializable trade for [`StateSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TradeSnapshotDto {
+    pub price: f64,
+    pub size: f64,
+    pub side: Side,
+    pub ts_ms: i64,
+}
+
+/// A serializable ticker update for [`StateSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TickerSnapshotDto {
+    pub last_price: f64,
+    pub ts_ms: i64,
+}
+
+/// A dashboard-facing, JSON-serializable snapshot of [`GlobalState`], keyed
+/// by each venue's [`ExchangeFeed::as_str`] name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StateSnapshot {
+    pub bbo: HashMap<String, BboSnapshotDto>,
+    pub trades: HashMap<String, Vec<TradeSnapshotDto>>,
+    pub tickers: HashMap<String, TickerSnapshotDto>,
+}
+
+/// Locks `state` once, converts every venue's BBO/trade/ticker snapshots
+/// into a [`StateSnapshot`], and serializes it to a JSON string.
+pub fn export_state_json(state: &Arc<Mutex<GlobalState>>) -> String {
+    let guard = state.lock().unwrap();
+    let mut snapshot = StateSnapshot::default();
+
+    for feed in ExchangeFeed::ALL {
+        let key = feed.as_str().to_string();
+
+        if let Some(bbo) = guard.bbo(feed) {
+            snapshot.bbo.insert(
+                key.clone(),
+                BboSnapshotDto {
+                    bid: bbo.bid,
+                    ask: bbo.ask,
+                    ts_ms: bbo.ts_ns / 1_000_000,
+                },
+            );
+        }
+
+        let trades: Vec<TradeSnapshotDto> = guard
+            .trades(feed)
+            .iter()
+            .map(|trade| TradeSnapshotDto {
+                price: trade.price,
+                size: trade.size,
+                side: trade.side,
+                ts_ms: trade.ts_ns / 1_000_000,
+            })
+            .collect();
+        if !trades.is_empty() {
+            snapshot.trades.insert(key.clone(), trades);
+        }
+
+        if let Some(ticker) = guard.ticker(feed) {
+            snapshot.tickers.insert(
+                key,
+                TickerSnapshotDto {
+                    last_price: ticker.last_price,
+                    ts_ms: ticker.ts_ns / 1_000_000,
+                },
+            );
+        }
+    }
+
+    serde_json::to_string(&snapshot).expect("StateSnapshot contains no non-serializable types")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base_classes::engine::{update_bbo_store, update_tickers, update_trades, Bbo, TickerUpdate, TradeUpdate};
+    use crate::base_classes::feed_gate::FeedTimestampGate;
+
+    #[test]
+    fn latest_bbo_clones_out_a_snapshot_without_holding_the_lock() {
+        let state = Arc::new(Mutex::new(GlobalState::new()));
+        let mut gate = FeedTimestampGate::new();
+        update_bbo_store(
+            &mut state.lock().unwrap(),
+            &mut gate,
+            ExchangeFeed::Kraken,
+            Bbo { bid: 100.0, ask: 100.5, ts_ns: 1000 },
+        );
+
+        let snapshot = latest_bbo(&state, ExchangeFeed::Kraken).unwrap();
+        assert_eq!(snapshot, BboSnapshot { bid: 100.0, ask: 100.5, ts_ns: 1000 });
+        assert!(latest_bbo(&state, ExchangeFeed::Binance).is_none());
+    }
+
+    #[test]
+    fn latest_bbo_if_fresh_rejects_a_snapshot_older_than_the_threshold() {
+        let state = Arc::new(Mutex::new(GlobalState::new()));
+        let mut gate = FeedTimestampGate::new();
+        update_bbo_store(
+            &mut state.lock().unwrap(),
+            &mut gate,
+            ExchangeFeed::Kraken,
+            Bbo { bid: 100.0, ask: 100.5, ts_ns: 1_000_000_000 },
+        );
+
+        let max_age = Duration::from_millis(500);
+        assert!(latest_bbo_if_fresh(&state, ExchangeFeed::Kraken, 1_100_000_000, max_age).is_some());
+        // A 2s gap in the feed is well past the 500ms freshness threshold.
+        assert!(latest_bbo_if_fresh(&state, ExchangeFeed::Kraken, 3_000_000_000, max_age).is_none());
+    }
+
+    #[test]
+    fn latest_mid_all_only_returns_venues_with_a_known_bbo() {
+        let state = Arc::new(Mutex::new(GlobalState::new()));
+        let mut gate = FeedTimestampGate::new();
+        update_bbo_store(
+            &mut state.lock().unwrap(),
+            &mut gate,
+            ExchangeFeed::Kraken,
+            Bbo { bid: 100.0, ask: 102.0, ts_ns: 1000 },
+        );
+        update_bbo_store(
+            &mut state.lock().unwrap(),
+            &mut gate,
+            ExchangeFeed::Coinbase,
+            Bbo { bid: 200.0, ask: 200.0, ts_ns: 1000 },
+        );
+
+        let mids = latest_mid_all(&state);
+        assert_eq!(mids.len(), 2);
+        assert!(mids.contains(&(ExchangeFeed::Kraken, 101.0)));
+        assert!(mids.contains(&(ExchangeFeed::Coinbase, 200.0)));
+    }
+
+    #[test]
+    fn export_state_json_contains_every_populated_exchanges_key() {
+        use crate::models::Side;
+
+        let state = Arc::new(Mutex::new(GlobalState::new()));
+        let mut gate = FeedTimestampGate::new();
+        let feeds = [
+            ExchangeFeed::Binance,
+            ExchangeFeed::Bybit,
+            ExchangeFeed::Kraken,
+            ExchangeFeed::Coinbase,
+            ExchangeFeed::Okx,
+        ];
+        for (i, &feed) in feeds.iter().enumerate() {
+            let ts_ns = 1_000_000_000 + i as i64;
+            update_bbo_store(&mut state.lock().unwrap(), &mut gate, feed, Bbo { bid: 100.0, ask: 100.5, ts_ns });
+            update_trades(
+                &mut state.lock().unwrap(),
+                &mut gate,
+                feed,
+                TradeUpdate { price: 100.25, size: 1.0, side: Side::Buy, ts_ns },
+            );
+            update_tickers(&mut state.lock().unwrap(), &mut gate, feed, TickerUpdate { last_price: 100.3, ts_ns });
+        }
+
+        let json = export_state_json(&state);
+        for feed in feeds {
+            let key = feed.as_str();
+            assert!(json.contains(&format!("\"{key}\"")), "missing key {key} in {json}");
+        }
+        assert!(json.contains("\"ts_ms\":1"));
+    }
+}
@@ -0,0 +1,154 @@
+//! Cross-venue basis (perpetual mark vs index price) computation for
+//! basis-trading strategies.
+//!
+//! Like funding rates, [`crate::base_classes::engine::GlobalState`] only
+//! tracks each venue's last-traded-price ticker — it has no notion of a
+//! perpetual's mark price or its index price. This module adds a small
+//! symbol-keyed ledger, mirroring [`crate::collectors::funding::FundingBook`].
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::base_classes::feed_gate::ExchangeFeed;
+
+/// One venue's latest mark price and, if the venue reports one, index
+/// price for a symbol.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkIndexSnapshot {
+    pub mark_price: f64,
+    pub index_price: Option<f64>,
+}
+
+/// A symbol-keyed ledger of each venue's latest [`MarkIndexSnapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct BasisBook {
+    by_symbol: HashMap<String, HashMap<ExchangeFeed, MarkIndexSnapshot>>,
+}
+
+impl BasisBook {
+    pub fn new() -> Self {
+        BasisBook::default()
+    }
+
+    pub fn set_mark_index(&mut self, symbol: &str, exchange: ExchangeFeed, snapshot: MarkIndexSnapshot) {
+        self.by_symbol.entry(symbol.to_string()).or_default().insert(exchange, snapshot);
+    }
+
+    /// Each venue's basis (mark minus index price) for `symbol`, skipping
+    /// any venue that hasn't reported an index price.
+    pub fn venue_basis(&self, symbol: &str) -> Vec<(ExchangeFeed, f64)> {
+        match self.by_symbol.get(symbol) {
+            Some(venues) => venues
+                .iter()
+                .filter_map(|(&exchange, snap)| snap.index_price.map(|index| (exchange, snap.mark_price - index)))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The mean basis across every venue with an index price for `symbol`,
+    /// `0.0` if none do, rather than dividing by zero.
+    pub fn consensus_basis(&self, symbol: &str) -> f64 {
+        let bases = self.venue_basis(symbol);
+        if bases.is_empty() {
+            0.0
+        } else {
+            bases.iter().map(|&(_, basis)| basis).sum::<f64>() / bases.len() as f64
+        }
+    }
+}
+
+/// A serializable per-venue basis row for [`export_consensus_basis_json`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct BasisRowDto {
+    exchange: String,
+    basis: f64,
+}
+
+/// A serializable consensus-basis response, the shape an HTTP endpoint
+/// would hand back for `symbol`. Mirrors
+/// [`crate::collectors::funding::export_consensus_funding_json`] — this
+/// crate has no HTTP server of its own to wire the endpoint into.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ConsensusBasisDto {
+    symbol: String,
+    venues: Vec<BasisRowDto>,
+    consensus_basis: f64,
+}
+
+/// Serializes `book`'s consensus basis view for `symbol` to a JSON string,
+/// for a caller that exposes it over an endpoint. Fails if a venue's basis
+/// is NaN or infinite — `serde_json` would otherwise silently encode it as
+/// `null`, which looks indistinguishable from "no data" to a consumer.
+pub fn export_consensus_basis_json(book: &BasisBook, symbol: &str) -> Result<String, serde_json::Error> {
+    use serde::ser::Error;
+
+    let dto = ConsensusBasisDto {
+        symbol: symbol.to_string(),
+        venues: book
+            .venue_basis(symbol)
+            .into_iter()
+            .map(|(exchange, basis)| BasisRowDto { exchange: exchange.as_str().to_string(), basis })
+            .collect(),
+        consensus_basis: book.consensus_basis(symbol),
+    };
+    if !dto.consensus_basis.is_finite() || dto.venues.iter().any(|row| !row.basis.is_finite()) {
+        return Err(serde_json::Error::custom("basis value is NaN or infinite"));
+    }
+    serde_json::to_string(&dto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basis_is_computed_correctly_from_mark_and_index() {
+        let mut book = BasisBook::new();
+        book.set_mark_index(
+            "BTCUSDT",
+            ExchangeFeed::Binance,
+            MarkIndexSnapshot { mark_price: 50_100.0, index_price: Some(50_000.0) },
+        );
+
+        let bases = book.venue_basis("BTCUSDT");
+        assert_eq!(bases, vec![(ExchangeFeed::Binance, 100.0)]);
+        assert_eq!(book.consensus_basis("BTCUSDT"), 100.0);
+    }
+
+    #[test]
+    fn a_venue_missing_an_index_price_is_excluded() {
+        let mut book = BasisBook::new();
+        book.set_mark_index(
+            "BTCUSDT",
+            ExchangeFeed::Binance,
+            MarkIndexSnapshot { mark_price: 50_100.0, index_price: Some(50_000.0) },
+        );
+        book.set_mark_index("BTCUSDT", ExchangeFeed::Bybit, MarkIndexSnapshot { mark_price: 50_200.0, index_price: None });
+
+        let bases = book.venue_basis("BTCUSDT");
+        assert_eq!(bases.len(), 1);
+        assert_eq!(bases[0].0, ExchangeFeed::Binance);
+        assert_eq!(book.consensus_basis("BTCUSDT"), 100.0);
+    }
+
+    #[test]
+    fn an_unknown_symbol_has_no_basis_to_report() {
+        let book = BasisBook::new();
+        assert!(book.venue_basis("ETHUSDT").is_empty());
+        assert_eq!(book.consensus_basis("ETHUSDT"), 0.0);
+    }
+
+    #[test]
+    fn a_non_finite_basis_is_reported_as_an_error_instead_of_a_silent_null() {
+        let mut book = BasisBook::new();
+        book.set_mark_index(
+            "BTCUSDT",
+            ExchangeFeed::Binance,
+            MarkIndexSnapshot { mark_price: f64::NAN, index_price: Some(50_000.0) },
+        );
+
+        assert!(export_consensus_basis_json(&book, "BTCUSDT").is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Translates Coinbase frames into [`MarketEvent`]s and applies them to
+//! [`GlobalState`], gated through [`FeedTimestampGate`] like every other
+//! venue's collector.
+
+use crate::base_classes::engine::{
+    update_bbo_store as engine_update_bbo_store, update_tickers as engine_update_tickers,
+    update_trades as engine_update_trades, Bbo, GlobalState, MarketEvent, TickerUpdate, TradeUpdate,
+};
+use crate::base_classes::feed_gate::{ExchangeFeed, FeedTimestampGate, GateDecision};
+use crate::exchanges::coinbase::{Coinbase, CoinbaseFrame, CoinbaseHandler};
+use crate::models::{normalize_trade_with_scale, Exchange, Side};
+
+/// Decodes one [`CoinbaseFrame`] into the [`MarketEvent`]s it produces,
+/// updating `handler`'s per-product book state along the way. A `Level2`
+/// frame only yields a [`MarketEvent::Bbo`] once both sides of the book are
+/// known. Trades are rounded using `handler`'s registered per-symbol price
+/// scale so a coarse-ticked and a fine-ticked product aren't forced to
+/// share one scale.
+pub fn events_for(handler: &mut CoinbaseHandler, frame: &CoinbaseFrame) -> Vec<MarketEvent> {
+    match frame {
+        CoinbaseFrame::Level2 { product_id, bids, asks, time_ns } => {
+            let book = handler.book_for(product_id);
+            book.apply_snapshot(bids, asks);
+            match (book.best_bid, book.best_ask) {
+                (Some(bid), Some(ask)) => vec![MarketEvent::Bbo(Bbo {
+                    bid: bid.0,
+                    ask: ask.0,
+                    ts_ns: *time_ns,
+                })],
+                _ => vec![],
+            }
+        }
+        CoinbaseFrame::MarketTrades { product_id, price, size, side, time_ns } => {
+            let side = if side == "sell" { Side::Sell } else { Side::Buy };
+            let price_scale = handler.price_scale(product_id);
+            let tick = normalize_trade_with_scale(
+                (*price * price_scale as f64).round() as i64,
+                (*size * Coinbase::QTY_SCALE as f64).round() as i64,
+                price_scale,
+                Coinbase::QTY_SCALE,
+                side,
+                *time_ns,
+            );
+            vec![MarketEvent::Trade(TradeUpdate {
+                price: tick.price,
+                size: tick.size,
+                side: tick.side,
+                ts_ns: tick.ts_ns,
+            })]
+        }
+        CoinbaseFrame::Ticker { price, time_ns, .. } => vec![MarketEvent::Ticker(TickerUpdate {
+            last_price: *price,
+            ts_ns: *time_ns,
+        })],
+    }
+}
+
+pub fn update_bbo_store(state: &mut GlobalState, gate: &mut FeedTimestampGate, bbo: Bbo) -> GateDecision {
+    engine_update_bbo_store(state, gate, ExchangeFeed::Coinbase, bbo)
+}
+
+pub fn update_trades(state: &mut GlobalState, gate: &mut FeedTimestampGate, trade: TradeUpdate) -> GateDecision {
+    engine_update_trades(state, gate, ExchangeFeed::Coinbase, trade)
+}
+
+pub fn update_tickers(state: &mut GlobalState, gate: &mut FeedTimestampGate, ticker: TickerUpdate) -> GateDecision {
+    engine_update_tickers(state, gate, ExchangeFeed::Coinbase, ticker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level2_frame_produces_a_bbo_event_once_both_sides_are_known() {
+        let mut handler = CoinbaseHandler::new();
+        let frame = CoinbaseFrame::Level2 {
+            product_id: "BTC-USD".to_string(),
+            bids: vec![(100.0, 1.0)],
+            asks: vec![(100.5, 1.0)],
+            time_ns: 1000,
+        };
+        let events = events_for(&mut handler, &frame);
+        assert_eq!(events, vec![MarketEvent::Bbo(Bbo { bid: 100.0, ask: 100.5, ts_ns: 1000 })]);
+    }
+
+    #[test]
+    fn market_trades_route_into_global_state_through_the_gate() {
+        let mut state = GlobalState::new();
+        let mut gate = FeedTimestampGate::new();
+        let mut handler = CoinbaseHandler::new();
+
+        let frame = CoinbaseFrame::MarketTrades {
+            product_id: "BTC-USD".to_string(),
+            price: 100.0,
+            size: 1.0,
+            side: "buy".to_string(),
+            time_ns: 1000,
+        };
+        for event in events_for(&mut handler, &frame) {
+            if let MarketEvent::Trade(trade) = event {
+                assert_eq!(update_trades(&mut state, &mut gate, trade), GateDecision::Accept);
+            }
+        }
+        assert_eq!(state.trades(ExchangeFeed::Coinbase).len(), 1);
+    }
+
+    #[test]
+    fn a_high_precision_and_a_low_precision_symbol_each_use_their_own_registered_scale() {
+        let mut handler = CoinbaseHandler::new();
+        handler.register_tick_size("BTC-USD", 0.01);
+        handler.register_tick_size("SHIB-USD", 0.00000001);
+
+        let btc_events = events_for(
+            &mut handler,
+            &CoinbaseFrame::MarketTrades {
+                product_id: "BTC-USD".to_string(),
+                price: 65_432.17,
+                size: 0.5,
+                side: "buy".to_string(),
+                time_ns: 1000,
+            },
+        );
+        assert_eq!(
+            btc_events,
+            vec![MarketEvent::Trade(TradeUpdate { price: 65_432.17, size: 0.5, side: Side::Buy, ts_ns: 1000 })]
+        );
+
+        let shib_events = events_for(
+            &mut handler,
+            &CoinbaseFrame::MarketTrades {
+                product_id: "SHIB-USD".to_string(),
+                price: 0.00001234,
+                size: 1_000_000.0,
+                side: "sell".to_string(),
+                time_ns: 2000,
+            },
+        );
+        assert_eq!(
+            shib_events,
+            vec![MarketEvent::Trade(TradeUpdate { price: 0.00001234, size: 1_000_000.0, side: Side::Sell, ts_ns: 2000 })]
+        );
+    }
+}
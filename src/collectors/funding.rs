@@ -0,0 +1,150 @@
+//! Cross-venue funding-rate aggregation for funding-rate arbitrage
+//! screening.
+//!
+//! [`crate::base_classes::engine::GlobalState`] tracks each venue's
+//! top-of-book, trades, and last-traded-price ticker, but has no notion of
+//! a perpetual's funding rate or next funding time. This module adds a
+//! small, symbol-keyed ledger collectors can update independently of that
+//! tick pipeline.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::base_classes::feed_gate::ExchangeFeed;
+
+/// One venue's latest funding-rate reading for a symbol.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FundingSnapshot {
+    pub rate: f64,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
+
+/// A symbol-keyed ledger of each venue's latest [`FundingSnapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct FundingBook {
+    by_symbol: HashMap<String, HashMap<ExchangeFeed, FundingSnapshot>>,
+}
+
+impl FundingBook {
+    pub fn new() -> Self {
+        FundingBook::default()
+    }
+
+    pub fn set_funding(&mut self, symbol: &str, exchange: ExchangeFeed, snapshot: FundingSnapshot) {
+        self.by_symbol.entry(symbol.to_string()).or_default().insert(exchange, snapshot);
+    }
+
+    /// Every venue's current funding rate and next funding time for
+    /// `symbol`, or an empty `Vec` if no venue has reported funding for it.
+    pub fn consensus_funding(&self, symbol: &str) -> Vec<(ExchangeFeed, f64, Option<DateTime<Utc>>)> {
+        match self.by_symbol.get(symbol) {
+            Some(venues) => venues.iter().map(|(&exchange, snap)| (exchange, snap.rate, snap.next_funding_time)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The spread between the highest and lowest funding rate reported for
+    /// `symbol`, `0.0` if fewer than two venues have data to compare.
+    pub fn funding_spread(&self, symbol: &str) -> f64 {
+        let rates: Vec<f64> = self.consensus_funding(symbol).into_iter().map(|(_, rate, _)| rate).collect();
+        if rates.len() < 2 {
+            return 0.0;
+        }
+        let max = rates.iter().cloned().fold(f64::MIN, f64::max);
+        let min = rates.iter().cloned().fold(f64::MAX, f64::min);
+        max - min
+    }
+}
+
+/// A serializable funding row for [`export_consensus_funding_json`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct FundingRowDto {
+    exchange: String,
+    rate: f64,
+    next_funding_time: Option<DateTime<Utc>>,
+}
+
+/// A serializable consensus-funding response, the shape an HTTP endpoint
+/// would hand back for `symbol`. Mirrors how
+/// [`crate::base_classes::state::export_state_json`] turns `GlobalState`
+/// into a dashboard-facing JSON string — this crate has no HTTP server of
+/// its own to wire the endpoint into.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ConsensusFundingDto {
+    symbol: String,
+    venues: Vec<FundingRowDto>,
+    spread: f64,
+}
+
+/// Serializes `book`'s consensus funding view for `symbol` to a JSON
+/// string, for a caller that exposes it over an endpoint. Fails if a
+/// venue's funding rate is NaN or infinite — `serde_json` would otherwise
+/// silently encode it as `null`, which looks indistinguishable from "no
+/// data" to a consumer.
+pub fn export_consensus_funding_json(book: &FundingBook, symbol: &str) -> Result<String, serde_json::Error> {
+    use serde::ser::Error;
+
+    let dto = ConsensusFundingDto {
+        symbol: symbol.to_string(),
+        venues: book
+            .consensus_funding(symbol)
+            .into_iter()
+            .map(|(exchange, rate, next_funding_time)| FundingRowDto {
+                exchange: exchange.as_str().to_string(),
+                rate,
+                next_funding_time,
+            })
+            .collect(),
+        spread: book.funding_spread(symbol),
+    };
+    if !dto.spread.is_finite() || dto.venues.iter().any(|row| !row.rate.is_finite()) {
+        return Err(serde_json::Error::custom("funding rate is NaN or infinite"));
+    }
+    serde_json::to_string(&dto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consensus_funding_reports_every_venues_rate_and_next_time_with_the_correct_spread() {
+        let mut book = FundingBook::new();
+        let next = Utc::now();
+        book.set_funding("BTCUSDT", ExchangeFeed::Binance, FundingSnapshot { rate: 0.0001, next_funding_time: Some(next) });
+        book.set_funding("BTCUSDT", ExchangeFeed::Bybit, FundingSnapshot { rate: 0.0003, next_funding_time: None });
+        book.set_funding("BTCUSDT", ExchangeFeed::Okx, FundingSnapshot { rate: -0.0002, next_funding_time: None });
+
+        let rows = book.consensus_funding("BTCUSDT");
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().any(|&(exchange, rate, time)| exchange == ExchangeFeed::Binance
+            && rate == 0.0001
+            && time == Some(next)));
+
+        assert!((book.funding_spread("BTCUSDT") - 0.0005).abs() < 1e-9);
+    }
+
+    #[test]
+    fn an_unknown_symbol_reports_no_venues_and_a_zero_spread() {
+        let book = FundingBook::new();
+        assert!(book.consensus_funding("ETHUSDT").is_empty());
+        assert_eq!(book.funding_spread("ETHUSDT"), 0.0);
+    }
+
+    #[test]
+    fn a_single_venue_has_no_spread_to_report() {
+        let mut book = FundingBook::new();
+        book.set_funding("BTCUSDT", ExchangeFeed::Kraken, FundingSnapshot { rate: 0.0001, next_funding_time: None });
+        assert_eq!(book.funding_spread("BTCUSDT"), 0.0);
+    }
+
+    #[test]
+    fn a_non_finite_funding_rate_is_reported_as_an_error_instead_of_a_silent_null() {
+        let mut book = FundingBook::new();
+        book.set_funding("BTCUSDT", ExchangeFeed::Kraken, FundingSnapshot { rate: f64::NAN, next_funding_time: None });
+
+        assert!(export_consensus_funding_json(&book, "BTCUSDT").is_err());
+    }
+}
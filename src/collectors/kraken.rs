@@ -0,0 +1,119 @@
+//! Translates Kraken Futures frames into [`MarketEvent`]s and applies them
+//! to [`GlobalState`], gated through [`FeedTimestampGate`] like every other
+//! venue's collector.
+
+use crate::base_classes::engine::{
+    update_bbo_store as engine_update_bbo_store, update_tickers as engine_update_tickers,
+    update_trades as engine_update_trades, Bbo, GlobalState, MarketEvent, TickerUpdate, TradeUpdate,
+};
+use crate::base_classes::feed_gate::{ExchangeFeed, FeedTimestampGate, GateDecision};
+use crate::exchanges::kraken::{Kraken, KrakenFrame, KrakenHandler};
+use crate::models::{normalize_trade_with_scale, Exchange, Side};
+
+/// Decodes one [`KrakenFrame`] into the [`MarketEvent`]s it produces,
+/// updating `handler`'s per-product book state along the way. A
+/// `book_snapshot` only yields a [`MarketEvent::Bbo`] once both sides of the
+/// book are known. Trades are rounded through [`normalize_trade_with_scale`],
+/// using `handler`'s registered per-symbol price scale so a coarse-ticked
+/// and a fine-ticked product aren't forced to share one scale.
+pub fn events_for(handler: &mut KrakenHandler, frame: &KrakenFrame) -> Vec<MarketEvent> {
+    match frame {
+        KrakenFrame::BookSnapshot { product_id, bids, asks, timestamp } => {
+            let book = handler.book_for(product_id);
+            book.apply_snapshot(bids, asks);
+            match (book.best_bid, book.best_ask) {
+                (Some(bid), Some(ask)) => vec![MarketEvent::Bbo(Bbo {
+                    bid: bid.0,
+                    ask: ask.0,
+                    ts_ns: *timestamp,
+                })],
+                _ => vec![],
+            }
+        }
+        KrakenFrame::Trade { product_id, price, qty, side, time } => {
+            let side = if side == "sell" { Side::Sell } else { Side::Buy };
+            let price_scale = handler.price_scale(product_id);
+            let tick = normalize_trade_with_scale(
+                (*price * price_scale as f64).round() as i64,
+                (*qty * Kraken::QTY_SCALE as f64).round() as i64,
+                price_scale,
+                Kraken::QTY_SCALE,
+                side,
+                *time,
+            );
+            vec![MarketEvent::Trade(TradeUpdate {
+                price: tick.price,
+                size: tick.size,
+                side: tick.side,
+                ts_ns: tick.ts_ns,
+            })]
+        }
+        KrakenFrame::Ticker { last, time, .. } => vec![MarketEvent::Ticker(TickerUpdate {
+            last_price: *last,
+            ts_ns: *time,
+        })],
+    }
+}
+
+pub fn update_bbo_store(state: &mut GlobalState, gate: &mut FeedTimestampGate, bbo: Bbo) -> GateDecision {
+    engine_update_bbo_store(state, gate, ExchangeFeed::Kraken, bbo)
+}
+
+pub fn update_trades(state: &mut GlobalState, gate: &mut FeedTimestampGate, trade: TradeUpdate) -> GateDecision {
+    engine_update_trades(state, gate, ExchangeFeed::Kraken, trade)
+}
+
+pub fn update_tickers(state: &mut GlobalState, gate: &mut FeedTimestampGate, ticker: TickerUpdate) -> GateDecision {
+    engine_update_tickers(state, gate, ExchangeFeed::Kraken, ticker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn book_snapshot_produces_a_bbo_event_once_both_sides_are_known() {
+        let mut handler = KrakenHandler::new();
+        let frame = KrakenFrame::BookSnapshot {
+            product_id: "PI_XBTUSD".to_string(),
+            bids: vec![(100.0, 1.0)],
+            asks: vec![(100.5, 1.0)],
+            timestamp: 1000,
+        };
+        let events = events_for(&mut handler, &frame);
+        assert_eq!(events, vec![MarketEvent::Bbo(Bbo { bid: 100.0, ask: 100.5, ts_ns: 1000 })]);
+    }
+
+    #[test]
+    fn trade_and_ticker_frames_route_into_global_state_through_the_gate() {
+        let mut state = GlobalState::new();
+        let mut gate = FeedTimestampGate::new();
+        let mut handler = KrakenHandler::new();
+
+        let trade_frame = KrakenFrame::Trade {
+            product_id: "PI_XBTUSD".to_string(),
+            price: 100.0,
+            qty: 1.0,
+            side: "buy".to_string(),
+            time: 1000,
+        };
+        for event in events_for(&mut handler, &trade_frame) {
+            if let MarketEvent::Trade(trade) = event {
+                assert_eq!(update_trades(&mut state, &mut gate, trade), GateDecision::Accept);
+            }
+        }
+        assert_eq!(state.trades(ExchangeFeed::Kraken).len(), 1);
+
+        let ticker_frame = KrakenFrame::Ticker {
+            product_id: "PI_XBTUSD".to_string(),
+            last: 100.25,
+            time: 1000,
+        };
+        for event in events_for(&mut handler, &ticker_frame) {
+            if let MarketEvent::Ticker(ticker) = event {
+                assert_eq!(update_tickers(&mut state, &mut gate, ticker), GateDecision::Accept);
+            }
+        }
+        assert_eq!(state.ticker(ExchangeFeed::Kraken).unwrap().last_price, 100.25);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod basis;
+pub mod coinbase;
+pub mod funding;
+pub mod kraken;
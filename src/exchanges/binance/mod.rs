@@ -0,0 +1,80 @@
+//! Binance order book snapshotting.
+
+use crate::models::Exchange;
+
+/// Marker type for Binance's [`Exchange`] scale, so raw scaled trades decode
+/// through the same [`Exchange::normalize_trade`] every other venue uses.
+pub struct Binance;
+
+impl Exchange for Binance {
+    const PRICE_SCALE: i64 = 100_000_000;
+    const QTY_SCALE: i64 = 100_000_000;
+}
+
+/// The `limit` values Binance's REST depth snapshot endpoint accepts.
+pub const ALLOWED_SNAPSHOT_DEPTHS: [u32; 8] = [5, 10, 20, 50, 100, 500, 1000, 5000];
+
+/// Rejects a REST snapshot depth Binance wouldn't accept.
+pub fn validate_snapshot_depth(depth: u32) -> Result<u32, String> {
+    if ALLOWED_SNAPSHOT_DEPTHS.contains(&depth) {
+        Ok(depth)
+    } else {
+        Err(format!(
+            "invalid Binance snapshot depth {depth}; must be one of {ALLOWED_SNAPSHOT_DEPTHS:?}"
+        ))
+    }
+}
+
+/// A locally-maintained order book seeded from Binance's REST depth
+/// snapshot, before websocket diffs are applied on top of it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BinanceBook {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+    pub depth: u32,
+}
+
+impl BinanceBook {
+    pub fn new() -> Self {
+        BinanceBook::default()
+    }
+
+    /// Records the validated snapshot depth this book was seeded at.
+    /// Fetching the snapshot itself over the network is the caller's
+    /// responsibility; this only guards against requesting a depth Binance
+    /// would reject.
+    pub fn init_from_rest(&mut self, depth: u32) -> Result<(), String> {
+        self.depth = validate_snapshot_depth(depth)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_depth_is_accepted_and_recorded() {
+        let mut book = BinanceBook::new();
+        assert!(book.init_from_rest(20).is_ok());
+        assert_eq!(book.depth, 20);
+    }
+
+    #[test]
+    fn invalid_depth_is_rejected() {
+        let mut book = BinanceBook::new();
+        assert!(book.init_from_rest(123).is_err());
+        assert_eq!(book.depth, 0);
+    }
+
+    #[test]
+    fn normalize_trade_scales_a_raw_binance_trade_into_a_canonical_tick() {
+        use crate::models::Side;
+
+        let tick = Binance::normalize_trade(3_000_000_000_000, 75_000_000, Side::Sell, 3_000);
+        assert_eq!(tick.price, 30_000.0);
+        assert_eq!(tick.size, 0.75);
+        assert_eq!(tick.side, Side::Sell);
+        assert_eq!(tick.ts_ns, 3_000);
+    }
+}
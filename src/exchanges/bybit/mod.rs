@@ -0,0 +1,184 @@
+//! Bybit derivatives websocket frame shapes and per-product book tracking.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::base_classes::parse_diagnostics::ParseDiagnostics;
+use crate::models::{Exchange, PriceScaleRegistry};
+
+/// Marker type for Bybit's [`Exchange`] scale, so raw scaled trades decode
+/// through the same [`Exchange::normalize_trade`] every other venue uses.
+pub struct Bybit;
+
+impl Exchange for Bybit {
+    const PRICE_SCALE: i64 = 100_000_000;
+    const QTY_SCALE: i64 = 100_000_000;
+}
+
+/// A decoded Bybit websocket message. Only the `orderbook`, `publicTrade`,
+/// and `tickers` topics are modeled; anything else is ignored by
+/// [`BybitHandler::parse_frame`]'s caller.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "topic", rename_all = "camelCase")]
+pub enum BybitFrame {
+    Orderbook {
+        symbol: String,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+        timestamp: i64,
+    },
+    #[serde(rename = "publicTrade")]
+    PublicTrade {
+        symbol: String,
+        price: f64,
+        qty: f64,
+        side: String,
+        time: i64,
+    },
+    Tickers {
+        symbol: String,
+        #[serde(rename = "lastPrice")]
+        last_price: f64,
+        time: i64,
+    },
+}
+
+/// Tracks the best bid/ask for one symbol from its most recent `orderbook`
+/// message. Bybit's incremental deltas aren't modeled here; only the top of
+/// book the BBO feed needs is kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BybitBook {
+    pub best_bid: Option<(f64, f64)>,
+    pub best_ask: Option<(f64, f64)>,
+}
+
+impl BybitBook {
+    pub fn apply_snapshot(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.best_bid = bids.iter().copied().max_by(|a, b| a.0.total_cmp(&b.0));
+        self.best_ask = asks.iter().copied().min_by(|a, b| a.0.total_cmp(&b.0));
+    }
+}
+
+/// Parses raw frames and keeps one [`BybitBook`] per symbol.
+#[derive(Debug, Default)]
+pub struct BybitHandler {
+    books: HashMap<String, BybitBook>,
+    price_scales: PriceScaleRegistry,
+    diagnostics: ParseDiagnostics,
+}
+
+impl BybitHandler {
+    pub fn new() -> Self {
+        BybitHandler::default()
+    }
+
+    pub fn parse_frame(raw: &str) -> Result<BybitFrame, String> {
+        serde_json::from_str(raw).map_err(|e| format!("invalid Bybit frame: {e}"))
+    }
+
+    /// Parses `raw` and records the outcome in [`BybitHandler::diagnostics`].
+    /// Unrecognized extra fields are tolerated by `serde`'s default
+    /// behavior; only a missing required field or malformed frame counts as
+    /// an error here, and neither ever panics.
+    pub fn parse(&mut self, raw: &str) -> Result<BybitFrame, String> {
+        let result = Self::parse_frame(raw);
+        self.diagnostics.record(&result);
+        result
+    }
+
+    /// Running counts of successful versus failed [`BybitHandler::parse`] calls.
+    pub fn diagnostics(&self) -> ParseDiagnostics {
+        self.diagnostics
+    }
+
+    pub fn book_for(&mut self, symbol: &str) -> &mut BybitBook {
+        self.books.entry(symbol.to_string()).or_default()
+    }
+
+    /// Registers `symbol`'s price scale, derived from its instrument spec's
+    /// tick size.
+    pub fn register_tick_size(&mut self, symbol: &str, tick_size: f64) {
+        self.price_scales.register(symbol, tick_size);
+    }
+
+    /// The price scale to decode `symbol`'s raw trades with: its registered
+    /// tick-size scale, or [`Bybit::PRICE_SCALE`] if none was registered.
+    pub fn price_scale(&self, symbol: &str) -> i64 {
+        self.price_scales.scale_for(symbol, Bybit::PRICE_SCALE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_an_orderbook_frame() {
+        let raw = r#"{"topic":"orderbook","symbol":"BTCUSDT","bids":[[100.0,1.0],[99.5,2.0]],"asks":[[100.5,1.0],[101.0,2.0]],"timestamp":1000}"#;
+        let frame = BybitHandler::parse_frame(raw).unwrap();
+        assert_eq!(
+            frame,
+            BybitFrame::Orderbook {
+                symbol: "BTCUSDT".to_string(),
+                bids: vec![(100.0, 1.0), (99.5, 2.0)],
+                asks: vec![(100.5, 1.0), (101.0, 2.0)],
+                timestamp: 1000,
+            }
+        );
+    }
+
+    #[test]
+    fn apply_snapshot_picks_the_best_bid_and_ask() {
+        let mut book = BybitBook::default();
+        book.apply_snapshot(&[(100.0, 1.0), (99.5, 2.0)], &[(100.5, 1.0), (101.0, 2.0)]);
+        assert_eq!(book.best_bid, Some((100.0, 1.0)));
+        assert_eq!(book.best_ask, Some((100.5, 1.0)));
+    }
+
+    #[test]
+    fn price_scale_resolves_distinct_scales_per_registered_symbol() {
+        let mut handler = BybitHandler::new();
+        handler.register_tick_size("BTCUSDT", 0.5);
+        handler.register_tick_size("SHIBUSDT", 0.00000001);
+
+        assert_eq!(handler.price_scale("BTCUSDT"), 10);
+        assert_eq!(handler.price_scale("SHIBUSDT"), 100_000_000);
+        assert_eq!(handler.price_scale("UNKNOWN"), Bybit::PRICE_SCALE);
+    }
+
+    #[test]
+    fn parse_tolerates_unexpected_extra_fields_and_counts_the_success() {
+        let raw = r#"{"topic":"tickers","symbol":"BTCUSDT","lastPrice":64251.0,"time":900,"fundingRate":0.0001}"#;
+        let mut handler = BybitHandler::new();
+        let frame = handler.parse(raw).unwrap();
+        assert_eq!(
+            frame,
+            BybitFrame::Tickers {
+                symbol: "BTCUSDT".to_string(),
+                last_price: 64251.0,
+                time: 900,
+            }
+        );
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 1, errors: 0 });
+    }
+
+    #[test]
+    fn parse_counts_a_missing_required_field_as_an_error_instead_of_panicking() {
+        let raw = r#"{"topic":"tickers","symbol":"BTCUSDT","time":900}"#;
+        let mut handler = BybitHandler::new();
+        assert!(handler.parse(raw).is_err());
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 0, errors: 1 });
+    }
+
+    #[test]
+    fn normalize_trade_scales_a_raw_bybit_trade_into_a_canonical_tick() {
+        use crate::models::Side;
+
+        let tick = Bybit::normalize_trade(5_012_345_600_000, 150_000_000, Side::Sell, 1_000);
+        assert_eq!(tick.price, 50_123.456);
+        assert_eq!(tick.size, 1.5);
+        assert_eq!(tick.side, Side::Sell);
+        assert_eq!(tick.ts_ns, 1_000);
+    }
+}
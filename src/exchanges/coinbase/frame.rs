@@ -0,0 +1,28 @@
+//! Coinbase Advanced Trade websocket frame shapes.
+
+use serde::Deserialize;
+
+/// A decoded Coinbase Advanced Trade websocket message. Only the `level2`,
+/// `market_trades`, and `ticker` channels are modeled.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum CoinbaseFrame {
+    Level2 {
+        product_id: String,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+        time_ns: i64,
+    },
+    MarketTrades {
+        product_id: String,
+        price: f64,
+        size: f64,
+        side: String,
+        time_ns: i64,
+    },
+    Ticker {
+        product_id: String,
+        price: f64,
+        time_ns: i64,
+    },
+}
@@ -0,0 +1,97 @@
+//! Per-product book state for the Coinbase feed.
+
+use std::collections::HashMap;
+
+use crate::base_classes::parse_diagnostics::ParseDiagnostics;
+use crate::models::PriceScaleRegistry;
+
+use super::orderbook::CoinbaseBook;
+use super::parser;
+use super::frame::CoinbaseFrame;
+use super::Coinbase;
+use crate::models::Exchange;
+
+#[derive(Debug, Default)]
+pub struct CoinbaseHandler {
+    books: HashMap<String, CoinbaseBook>,
+    price_scales: PriceScaleRegistry,
+    diagnostics: ParseDiagnostics,
+}
+
+impl CoinbaseHandler {
+    pub fn new() -> Self {
+        CoinbaseHandler::default()
+    }
+
+    pub fn book_for(&mut self, product_id: &str) -> &mut CoinbaseBook {
+        self.books.entry(product_id.to_string()).or_default()
+    }
+
+    /// Parses `raw` and records the outcome in [`CoinbaseHandler::diagnostics`].
+    /// Unrecognized extra fields are tolerated by `serde`'s default
+    /// behavior; only a missing required field or malformed frame counts as
+    /// an error here, and neither ever panics.
+    pub fn parse(&mut self, raw: &str) -> Result<CoinbaseFrame, String> {
+        let result = parser::parse_frame(raw);
+        self.diagnostics.record(&result);
+        result
+    }
+
+    /// Running counts of successful versus failed [`CoinbaseHandler::parse`] calls.
+    pub fn diagnostics(&self) -> ParseDiagnostics {
+        self.diagnostics
+    }
+
+    /// Registers `product_id`'s price scale, derived from its instrument
+    /// spec's tick size.
+    pub fn register_tick_size(&mut self, product_id: &str, tick_size: f64) {
+        self.price_scales.register(product_id, tick_size);
+    }
+
+    /// The price scale to decode `product_id`'s raw trades with: its
+    /// registered tick-size scale, or [`Coinbase::PRICE_SCALE`] if none was
+    /// registered.
+    pub fn price_scale(&self, product_id: &str) -> i64 {
+        self.price_scales.scale_for(product_id, Coinbase::PRICE_SCALE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tolerates_unexpected_extra_fields_and_counts_the_success() {
+        let raw = r#"{"channel":"ticker","product_id":"BTC-USD","price":64101.0,"time_ns":900,"volume_24h":1234.5}"#;
+        let mut handler = CoinbaseHandler::new();
+        let frame = handler.parse(raw).unwrap();
+        assert_eq!(
+            frame,
+            CoinbaseFrame::Ticker {
+                product_id: "BTC-USD".to_string(),
+                price: 64101.0,
+                time_ns: 900,
+            }
+        );
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 1, errors: 0 });
+    }
+
+    #[test]
+    fn parse_counts_a_missing_required_field_as_an_error_instead_of_panicking() {
+        let raw = r#"{"channel":"ticker","product_id":"BTC-USD","time_ns":900}"#;
+        let mut handler = CoinbaseHandler::new();
+        assert!(handler.parse(raw).is_err());
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 0, errors: 1 });
+    }
+
+    #[test]
+    fn price_scale_resolves_distinct_scales_per_registered_product() {
+        let mut handler = CoinbaseHandler::new();
+        handler.register_tick_size("BTC-USD", 0.01);
+        handler.register_tick_size("SHIB-USD", 0.00000001);
+
+        assert_eq!(handler.price_scale("BTC-USD"), 100);
+        assert_eq!(handler.price_scale("SHIB-USD"), 100_000_000);
+        assert_eq!(handler.price_scale("UNKNOWN-USD"), Coinbase::PRICE_SCALE);
+    }
+}
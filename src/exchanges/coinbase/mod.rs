@@ -0,0 +1,61 @@
+//! Coinbase Advanced Trade market data: websocket frame shapes (`frame`),
+//! raw-text decoding (`parser`), per-product book state (`handler`), and
+//! top-of-book tracking (`orderbook`).
+
+pub mod frame;
+pub mod handler;
+pub mod orderbook;
+pub mod parser;
+
+pub use frame::CoinbaseFrame;
+pub use handler::CoinbaseHandler;
+pub use orderbook::CoinbaseBook;
+
+use crate::models::Exchange;
+
+/// Marker type for Coinbase's [`Exchange`] scale, so raw scaled trades
+/// decode through the same [`Exchange::normalize_trade`] every other venue
+/// uses.
+pub struct Coinbase;
+
+impl Exchange for Coinbase {
+    const PRICE_SCALE: i64 = 100_000_000;
+    const QTY_SCALE: i64 = 100_000_000;
+}
+
+/// Checks `symbol` against the product ids returned by Coinbase's
+/// `/products` endpoint, so a feed can be disabled before it ever opens a
+/// websocket connection for a symbol the venue doesn't list. Takes the
+/// already-fetched product list rather than calling the endpoint itself, so
+/// this stays pure and testable; fetching the list is the caller's job.
+pub fn coinbase_symbol_supported(products: &[String], symbol: &str) -> bool {
+    products.iter().any(|p| p == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listed_symbol_is_supported() {
+        let products = vec!["BTC-USD".to_string(), "ETH-USD".to_string()];
+        assert!(coinbase_symbol_supported(&products, "BTC-USD"));
+    }
+
+    #[test]
+    fn unlisted_symbol_is_not_supported() {
+        let products = vec!["BTC-USD".to_string()];
+        assert!(!coinbase_symbol_supported(&products, "DOGE-USD"));
+    }
+
+    #[test]
+    fn normalize_trade_scales_a_raw_coinbase_trade_into_a_canonical_tick() {
+        use crate::models::Side;
+
+        let tick = Coinbase::normalize_trade(6_789_012_300_000, 250_000_000, Side::Buy, 2_000);
+        assert_eq!(tick.price, 67_890.123);
+        assert_eq!(tick.size, 2.5);
+        assert_eq!(tick.side, Side::Buy);
+        assert_eq!(tick.ts_ns, 2_000);
+    }
+}
@@ -0,0 +1,29 @@
+//! Local top-of-book tracking for Coinbase `level2` snapshots.
+
+/// Tracks the best bid/ask for one product from its most recent `level2`
+/// update.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoinbaseBook {
+    pub best_bid: Option<(f64, f64)>,
+    pub best_ask: Option<(f64, f64)>,
+}
+
+impl CoinbaseBook {
+    pub fn apply_snapshot(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.best_bid = bids.iter().copied().max_by(|a, b| a.0.total_cmp(&b.0));
+        self.best_ask = asks.iter().copied().min_by(|a, b| a.0.total_cmp(&b.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_snapshot_picks_the_best_bid_and_ask() {
+        let mut book = CoinbaseBook::default();
+        book.apply_snapshot(&[(100.0, 1.0), (99.5, 2.0)], &[(100.5, 1.0), (101.0, 2.0)]);
+        assert_eq!(book.best_bid, Some((100.0, 1.0)));
+        assert_eq!(book.best_ask, Some((100.5, 1.0)));
+    }
+}
@@ -0,0 +1,27 @@
+//! Raw-text decoding of Coinbase websocket frames.
+
+use super::frame::CoinbaseFrame;
+
+pub fn parse_frame(raw: &str) -> Result<CoinbaseFrame, String> {
+    serde_json::from_str(raw).map_err(|e| format!("invalid Coinbase frame: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_level2_frame() {
+        let raw = r#"{"channel":"level2","product_id":"BTC-USD","bids":[[100.0,1.0]],"asks":[[100.5,1.0]],"time_ns":1000}"#;
+        let frame = parse_frame(raw).unwrap();
+        assert_eq!(
+            frame,
+            CoinbaseFrame::Level2 {
+                product_id: "BTC-USD".to_string(),
+                bids: vec![(100.0, 1.0)],
+                asks: vec![(100.5, 1.0)],
+                time_ns: 1000,
+            }
+        );
+    }
+}
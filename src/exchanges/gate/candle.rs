@@ -0,0 +1,104 @@
+//! Gate.io candlestick (OHLC) decoding. Gate's REST candlestick endpoint
+//! returns each row as either a plain
+//! `[timestamp, volume, close, high, low, open]` array of strings (the
+//! older format) or an object with named fields (the newer one); both
+//! normalize to the same [`Ohlc`] so a caller doesn't need to know which
+//! shape it got.
+
+use serde::Deserialize;
+
+/// One normalized OHLC candle, independent of which wire format it arrived
+/// in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ohlc {
+    pub ts_ns: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum GateCandleRow {
+    /// `[timestamp, volume, close, high, low, open]`, all as strings.
+    Array(String, String, String, String, String, String),
+    Object {
+        t: String,
+        v: String,
+        c: String,
+        h: String,
+        l: String,
+        o: String,
+    },
+}
+
+/// Parses one raw Gate candlestick row, in either wire format, into a
+/// canonical [`Ohlc`].
+pub fn parse_gate_candle(raw: &str) -> Result<Ohlc, String> {
+    let row: GateCandleRow = serde_json::from_str(raw).map_err(|e| format!("invalid Gate candle: {e}"))?;
+    let (t, v, c, h, l, o) = match row {
+        GateCandleRow::Array(t, v, c, h, l, o) => (t, v, c, h, l, o),
+        GateCandleRow::Object { t, v, c, h, l, o } => (t, v, c, h, l, o),
+    };
+    build_ohlc(&t, &o, &h, &l, &c, &v)
+}
+
+fn build_ohlc(t: &str, o: &str, h: &str, l: &str, c: &str, v: &str) -> Result<Ohlc, String> {
+    let parse_field = |s: &str, field: &str| s.parse::<f64>().map_err(|e| format!("invalid Gate candle {field}: {e}"));
+    let ts_secs: i64 = t.parse().map_err(|e| format!("invalid Gate candle timestamp: {e}"))?;
+    Ok(Ohlc {
+        ts_ns: ts_secs * 1_000_000_000,
+        open: parse_field(o, "open")?,
+        high: parse_field(h, "high")?,
+        low: parse_field(l, "low")?,
+        close: parse_field(c, "close")?,
+        volume: parse_field(v, "volume")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_array_candle_format() {
+        let raw = r#"["1700000000","12.5","100.5","101.0","99.5","100.0"]"#;
+        let candle = parse_gate_candle(raw).unwrap();
+        assert_eq!(
+            candle,
+            Ohlc {
+                ts_ns: 1_700_000_000_000_000_000,
+                open: 100.0,
+                high: 101.0,
+                low: 99.5,
+                close: 100.5,
+                volume: 12.5,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_the_object_candle_format_to_the_same_shape() {
+        let raw = r#"{"t":"1700000000","v":"12.5","c":"100.5","h":"101.0","l":"99.5","o":"100.0"}"#;
+        let candle = parse_gate_candle(raw).unwrap();
+        assert_eq!(
+            candle,
+            Ohlc {
+                ts_ns: 1_700_000_000_000_000_000,
+                open: 100.0,
+                high: 101.0,
+                low: 99.5,
+                close: 100.5,
+                volume: 12.5,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_a_malformed_candle_instead_of_panicking() {
+        let raw = r#"["not-a-timestamp","12.5","100.5","101.0","99.5","100.0"]"#;
+        assert!(parse_gate_candle(raw).is_err());
+    }
+}
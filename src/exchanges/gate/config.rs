@@ -0,0 +1,89 @@
+//! Settle-currency configuration for Gate execution: which contracts a
+//! `GateWsConfig` trades determines the account and contract-meta
+//! endpoints it should use.
+
+/// The settle currencies Gate execution supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettleCurrency {
+    Usdt,
+    Btc,
+}
+
+impl SettleCurrency {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "usdt" => Ok(SettleCurrency::Usdt),
+            "btc" => Ok(SettleCurrency::Btc),
+            other => Err(format!("unsupported Gate settle currency {other:?}; expected \"usdt\" or \"btc\"")),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettleCurrency::Usdt => "usdt",
+            SettleCurrency::Btc => "btc",
+        }
+    }
+}
+
+/// Configuration threaded through account fetch and contract-meta lookups
+/// for Gate execution, previously hardcoded to `"usdt"`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateWsConfig {
+    pub settle: SettleCurrency,
+}
+
+impl GateWsConfig {
+    pub fn new(settle: &str) -> Result<Self, String> {
+        Ok(GateWsConfig { settle: SettleCurrency::parse(settle)? })
+    }
+}
+
+/// The account endpoint for `config`'s settle currency, e.g.
+/// `/api/v4/futures/usdt/accounts`.
+pub fn account_endpoint(config: &GateWsConfig) -> String {
+    format!("/api/v4/futures/{}/accounts", config.settle.as_str())
+}
+
+/// Per-contract metadata, tagged with the settle currency it was resolved
+/// under so downstream PnL/fee math can pick the right settle asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GateContractMeta {
+    pub contract: String,
+    pub settle: SettleCurrency,
+}
+
+impl GateContractMeta {
+    pub fn new(contract: &str, config: &GateWsConfig) -> Self {
+        GateContractMeta {
+            contract: contract.to_string(),
+            settle: config.settle,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_non_usdt_settle_is_propagated_through_the_config_path() {
+        let config = GateWsConfig::new("btc").unwrap();
+        assert_eq!(config.settle, SettleCurrency::Btc);
+        assert_eq!(account_endpoint(&config), "/api/v4/futures/btc/accounts");
+
+        let meta = GateContractMeta::new("BTC_USD", &config);
+        assert_eq!(meta.settle, SettleCurrency::Btc);
+    }
+
+    #[test]
+    fn default_usdt_settle_still_works() {
+        let config = GateWsConfig::new("usdt").unwrap();
+        assert_eq!(account_endpoint(&config), "/api/v4/futures/usdt/accounts");
+    }
+
+    #[test]
+    fn rejects_an_unsupported_settle_currency() {
+        assert!(GateWsConfig::new("eth").is_err());
+    }
+}
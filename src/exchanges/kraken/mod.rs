@@ -0,0 +1,183 @@
+//! Kraken Futures websocket frame shapes and per-product book tracking.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::base_classes::parse_diagnostics::ParseDiagnostics;
+use crate::models::{Exchange, PriceScaleRegistry};
+
+/// Marker type for Kraken's [`Exchange`] scale, so raw scaled trades decode
+/// through the same [`Exchange::normalize_trade`] every other venue uses.
+pub struct Kraken;
+
+impl Exchange for Kraken {
+    const PRICE_SCALE: i64 = 100_000_000;
+    const QTY_SCALE: i64 = 100_000_000;
+}
+
+/// A decoded Kraken Futures websocket message. Only the `book_snapshot`,
+/// `trade`, and `ticker` feeds are modeled; anything else is ignored by
+/// [`KrakenHandler::parse_frame`]'s caller.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(tag = "feed", rename_all = "snake_case")]
+pub enum KrakenFrame {
+    BookSnapshot {
+        product_id: String,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+        timestamp: i64,
+    },
+    Trade {
+        product_id: String,
+        price: f64,
+        qty: f64,
+        side: String,
+        time: i64,
+    },
+    Ticker {
+        product_id: String,
+        last: f64,
+        time: i64,
+    },
+}
+
+/// Tracks the best bid/ask for one product from its most recent
+/// `book_snapshot`. Kraken's incremental `book` deltas aren't modeled here;
+/// only the top of book the BBO feed needs is kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct KrakenBook {
+    pub best_bid: Option<(f64, f64)>,
+    pub best_ask: Option<(f64, f64)>,
+}
+
+impl KrakenBook {
+    pub fn apply_snapshot(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.best_bid = bids.iter().copied().max_by(|a, b| a.0.total_cmp(&b.0));
+        self.best_ask = asks.iter().copied().min_by(|a, b| a.0.total_cmp(&b.0));
+    }
+}
+
+/// Parses raw frames and keeps one [`KrakenBook`] per product.
+#[derive(Debug, Default)]
+pub struct KrakenHandler {
+    books: HashMap<String, KrakenBook>,
+    price_scales: PriceScaleRegistry,
+    diagnostics: ParseDiagnostics,
+}
+
+impl KrakenHandler {
+    pub fn new() -> Self {
+        KrakenHandler::default()
+    }
+
+    pub fn parse_frame(raw: &str) -> Result<KrakenFrame, String> {
+        serde_json::from_str(raw).map_err(|e| format!("invalid Kraken frame: {e}"))
+    }
+
+    /// Parses `raw` and records the outcome in [`KrakenHandler::diagnostics`].
+    /// Unrecognized extra fields are tolerated by `serde`'s default
+    /// behavior; only a missing required field or malformed frame counts as
+    /// an error here, and neither ever panics.
+    pub fn parse(&mut self, raw: &str) -> Result<KrakenFrame, String> {
+        let result = Self::parse_frame(raw);
+        self.diagnostics.record(&result);
+        result
+    }
+
+    /// Running counts of successful versus failed [`KrakenHandler::parse`] calls.
+    pub fn diagnostics(&self) -> ParseDiagnostics {
+        self.diagnostics
+    }
+
+    pub fn book_for(&mut self, product_id: &str) -> &mut KrakenBook {
+        self.books.entry(product_id.to_string()).or_default()
+    }
+
+    /// Registers `product_id`'s price scale, derived from its instrument
+    /// spec's tick size.
+    pub fn register_tick_size(&mut self, product_id: &str, tick_size: f64) {
+        self.price_scales.register(product_id, tick_size);
+    }
+
+    /// The price scale to decode `product_id`'s raw trades with: its
+    /// registered tick-size scale, or [`Kraken::PRICE_SCALE`] if none was
+    /// registered.
+    pub fn price_scale(&self, product_id: &str) -> i64 {
+        self.price_scales.scale_for(product_id, Kraken::PRICE_SCALE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_book_snapshot_frame() {
+        let raw = r#"{"feed":"book_snapshot","product_id":"PI_XBTUSD","bids":[[100.0,1.0],[99.5,2.0]],"asks":[[100.5,1.0],[101.0,2.0]],"timestamp":1000}"#;
+        let frame = KrakenHandler::parse_frame(raw).unwrap();
+        assert_eq!(
+            frame,
+            KrakenFrame::BookSnapshot {
+                product_id: "PI_XBTUSD".to_string(),
+                bids: vec![(100.0, 1.0), (99.5, 2.0)],
+                asks: vec![(100.5, 1.0), (101.0, 2.0)],
+                timestamp: 1000,
+            }
+        );
+    }
+
+    #[test]
+    fn apply_snapshot_picks_the_best_bid_and_ask() {
+        let mut book = KrakenBook::default();
+        book.apply_snapshot(&[(100.0, 1.0), (99.5, 2.0)], &[(100.5, 1.0), (101.0, 2.0)]);
+        assert_eq!(book.best_bid, Some((100.0, 1.0)));
+        assert_eq!(book.best_ask, Some((100.5, 1.0)));
+    }
+
+    #[test]
+    fn price_scale_resolves_distinct_scales_per_registered_product() {
+        let mut handler = KrakenHandler::new();
+        handler.register_tick_size("PI_XBTUSD", 0.5);
+        handler.register_tick_size("PI_SHIBUSD", 0.00000001);
+
+        assert_eq!(handler.price_scale("PI_XBTUSD"), 10);
+        assert_eq!(handler.price_scale("PI_SHIBUSD"), 100_000_000);
+        assert_eq!(handler.price_scale("PI_UNKNOWN"), Kraken::PRICE_SCALE);
+    }
+
+    #[test]
+    fn parse_tolerates_unexpected_extra_fields_and_counts_the_success() {
+        let raw = r#"{"feed":"ticker","product_id":"PI_XBTUSD","last":64251.0,"time":900,"funding_rate":0.0001}"#;
+        let mut handler = KrakenHandler::new();
+        let frame = handler.parse(raw).unwrap();
+        assert_eq!(
+            frame,
+            KrakenFrame::Ticker {
+                product_id: "PI_XBTUSD".to_string(),
+                last: 64251.0,
+                time: 900,
+            }
+        );
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 1, errors: 0 });
+    }
+
+    #[test]
+    fn parse_counts_a_missing_required_field_as_an_error_instead_of_panicking() {
+        let raw = r#"{"feed":"ticker","product_id":"PI_XBTUSD","time":900}"#;
+        let mut handler = KrakenHandler::new();
+        assert!(handler.parse(raw).is_err());
+        assert_eq!(handler.diagnostics(), ParseDiagnostics { parsed: 0, errors: 1 });
+    }
+
+    #[test]
+    fn normalize_trade_scales_a_raw_kraken_trade_into_a_canonical_tick() {
+        use crate::models::Side;
+
+        let tick = Kraken::normalize_trade(5_012_345_600_000, 150_000_000, Side::Sell, 1_000);
+        assert_eq!(tick.price, 50_123.456);
+        assert_eq!(tick.size, 1.5);
+        assert_eq!(tick.side, Side::Sell);
+        assert_eq!(tick.ts_ns, 1_000);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod binance;
+pub mod bybit;
+pub mod coinbase;
+pub mod gate;
+pub mod kraken;
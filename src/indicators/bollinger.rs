@@ -0,0 +1,89 @@
+use super::{IndicatorValue, TechnicalIndicator};
+
+/// Bollinger Bands: a rolling SMA with upper/lower bands offset by
+/// `std_dev` sample standard deviations.
+pub struct BollingerBands {
+    pub period: usize,
+    pub std_dev: f64,
+}
+
+impl BollingerBands {
+    pub fn new(period: usize, std_dev: f64) -> Self {
+        BollingerBands { period, std_dev }
+    }
+
+    /// Returns `(upper, middle, lower)` for the latest window of `prices`.
+    fn bands(&self, prices: &[f64]) -> Result<(f64, f64, f64), String> {
+        if self.period < 2 {
+            return Err("Bollinger period must be at least 2".to_string());
+        }
+        if prices.len() < self.period {
+            return Err(format!(
+                "need at least {} prices for a period-{} Bollinger band, got {}",
+                self.period,
+                self.period,
+                prices.len()
+            ));
+        }
+
+        let window = &prices[prices.len() - self.period..];
+        let middle = window.iter().sum::<f64>() / self.period as f64;
+        // Sample standard deviation (n-1).
+        let variance = window.iter().map(|p| (p - middle).powi(2)).sum::<f64>() / (self.period - 1) as f64;
+        let std = variance.sqrt();
+
+        let upper = middle + self.std_dev * std;
+        let lower = middle - self.std_dev * std;
+        Ok((upper, middle, lower))
+    }
+
+    /// Where `price` sits relative to the latest bands: `0.0` at the lower
+    /// band, `1.0` at the upper band (and outside that range if price is
+    /// currently beyond the bands).
+    pub fn percent_b(&self, prices: &[f64], price: f64) -> Result<f64, String> {
+        let (upper, _, lower) = self.bands(prices)?;
+        if upper == lower {
+            return Ok(0.5);
+        }
+        Ok((price - lower) / (upper - lower))
+    }
+}
+
+impl TechnicalIndicator for BollingerBands {
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String> {
+        let (upper, middle, lower) = self.bands(prices)?;
+        Ok(IndicatorValue::Vector(vec![upper, middle, lower]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middle_band_equals_the_sma() {
+        let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 19.0, 20.0];
+        let bb = BollingerBands::new(10, 2.0);
+        let IndicatorValue::Vector(v) = bb.calculate(&prices).unwrap() else {
+            panic!("expected Vector");
+        };
+        let window = &prices[prices.len() - 10..];
+        let expected = window.iter().sum::<f64>() / window.len() as f64;
+        assert!((v[1] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn percent_b_is_zero_at_lower_and_one_at_upper() {
+        let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 19.0, 20.0];
+        let bb = BollingerBands::new(10, 2.0);
+        let (upper, _, lower) = bb.bands(&prices).unwrap();
+        assert!((bb.percent_b(&prices, lower).unwrap() - 0.0).abs() < 1e-9);
+        assert!((bb.percent_b(&prices, upper).unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn errors_with_too_few_prices() {
+        let bb = BollingerBands::new(20, 2.0);
+        assert!(bb.calculate(&[1.0, 2.0]).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Tracks the trailing price delta over a fixed time window (e.g. 3h or
+//! 24h). Unlike the other indicators, which work off a uniform
+//! closing-price series, this is fed tick by tick since trades arrive at
+//! irregular intervals.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::models::TradeTick;
+
+/// The rolling price delta (latest minus oldest price still in the window)
+/// over a configurable trailing duration.
+#[derive(Debug, Clone)]
+pub struct DeltaCalculator {
+    window: Duration,
+    samples: VecDeque<(i64, f64)>,
+}
+
+impl DeltaCalculator {
+    pub fn new(window: Duration) -> Self {
+        DeltaCalculator { window, samples: VecDeque::new() }
+    }
+
+    /// Seeds the calculator from ticks recorded before the tradeable period
+    /// begins, so a delta is available immediately instead of needing a
+    /// full window of live ticks first.
+    pub fn prewarm(&mut self, history: &[TradeTick]) {
+        for tick in history {
+            self.record(tick);
+        }
+    }
+
+    /// Records one tick, evicting any sample now outside the window.
+    pub fn record(&mut self, tick: &TradeTick) {
+        self.samples.push_back((tick.ts_ns, tick.price));
+        self.evict_stale(tick.ts_ns);
+    }
+
+    fn evict_stale(&mut self, now_ns: i64) {
+        let window_ns = self.window.as_nanos() as i64;
+        while let Some(&(ts_ns, _)) = self.samples.front() {
+            if now_ns - ts_ns > window_ns {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// The current price minus the oldest price still within the window, or
+    /// `None` with fewer than two samples.
+    pub fn delta(&self) -> Option<f64> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        let oldest = self.samples.front()?.1;
+        let latest = self.samples.back()?.1;
+        Some(latest - oldest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Side;
+
+    fn tick(ts_ns: i64, price: f64) -> TradeTick {
+        TradeTick { ts_ns, price, size: 1.0, side: Side::Buy, best_bid: None, best_ask: None }
+    }
+
+    #[test]
+    fn delta_is_none_with_fewer_than_two_samples() {
+        let mut calc = DeltaCalculator::new(Duration::from_secs(3 * 3600));
+        assert_eq!(calc.delta(), None);
+        calc.record(&tick(0, 100.0));
+        assert_eq!(calc.delta(), None);
+    }
+
+    #[test]
+    fn delta_is_the_latest_minus_the_oldest_in_window_price() {
+        let mut calc = DeltaCalculator::new(Duration::from_secs(3 * 3600));
+        calc.record(&tick(0, 100.0));
+        calc.record(&tick(3_600_000_000_000, 105.0));
+        assert_eq!(calc.delta(), Some(5.0));
+    }
+
+    #[test]
+    fn samples_older_than_the_window_are_evicted() {
+        let mut calc = DeltaCalculator::new(Duration::from_secs(3600));
+        calc.record(&tick(0, 100.0));
+        calc.record(&tick(2 * 3600 * 1_000_000_000, 110.0));
+        // The first sample is 2h stale against a 1h window; only the latest remains.
+        assert_eq!(calc.delta(), None);
+    }
+
+    #[test]
+    fn prewarming_from_history_matches_feeding_the_full_history_live() {
+        let window = Duration::from_secs(3 * 3600);
+        let history: Vec<TradeTick> = (0..10).map(|i| tick(i * 3600 * 1_000_000_000, 100.0 + i as f64)).collect();
+
+        let mut from_scratch = DeltaCalculator::new(window);
+        for t in &history {
+            from_scratch.record(t);
+        }
+
+        let mut prewarmed = DeltaCalculator::new(window);
+        prewarmed.prewarm(&history);
+
+        assert_eq!(prewarmed.delta(), from_scratch.delta());
+    }
+}
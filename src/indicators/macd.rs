@@ -0,0 +1,155 @@
+use super::{IndicatorValue, TechnicalIndicator};
+
+/// Moving Average Convergence/Divergence: fast EMA minus slow EMA, smoothed
+/// again by a signal-line EMA.
+pub struct MACD {
+    pub fast: usize,
+    pub slow: usize,
+    pub signal: usize,
+}
+
+impl MACD {
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        MACD { fast, slow, signal }
+    }
+
+    /// Returns the full MACD/signal/histogram series, one triple per input
+    /// bar; bars before the signal line has warmed up (the slow EMA, then
+    /// `signal` MACD values) are all `NaN`.
+    fn series(&self, prices: &[f64]) -> Result<Vec<[f64; 3]>, String> {
+        if self.fast == 0 || self.slow == 0 || self.signal == 0 {
+            return Err("MACD periods must be non-zero".to_string());
+        }
+        if self.fast >= self.slow {
+            return Err("fast period must be shorter than slow period".to_string());
+        }
+        if prices.len() < self.slow {
+            return Err(format!(
+                "need at least {} prices for a {}/{} MACD, got {}",
+                self.slow,
+                self.fast,
+                self.slow,
+                prices.len()
+            ));
+        }
+
+        let fast_ema = ema_series(prices, self.fast);
+        let slow_ema = ema_series(prices, self.slow);
+
+        let macd_line: Vec<f64> = fast_ema
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(f, s)| f - s)
+            .collect();
+
+        // The signal line only makes sense once the slow EMA has started
+        // producing real values, i.e. from index `slow - 1` onward.
+        let warm = self.slow - 1;
+        let signal_ema = ema_series(&macd_line[warm..], self.signal);
+
+        let mut out = Vec::with_capacity(prices.len());
+        for i in 0..prices.len() {
+            if i < warm + self.signal - 1 {
+                out.push([f64::NAN, f64::NAN, f64::NAN]);
+            } else {
+                let macd = macd_line[i];
+                let signal = signal_ema[i - warm];
+                out.push([macd, signal, macd - signal]);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns `Some("Crossover")`-style signal metadata if the MACD line
+    /// crosses the signal line between the last two bars.
+    pub fn crossover(&self, prices: &[f64]) -> Result<Option<IndicatorValue>, String> {
+        let series = self.series(prices)?;
+        if series.len() < 2 {
+            return Ok(None);
+        }
+        let [prev_macd, prev_signal, _] = series[series.len() - 2];
+        let [macd, signal, _] = series[series.len() - 1];
+        if prev_macd.is_nan() || macd.is_nan() {
+            return Ok(None);
+        }
+        let crossed_up = prev_macd <= prev_signal && macd > signal;
+        let crossed_down = prev_macd >= prev_signal && macd < signal;
+        if crossed_up {
+            Ok(Some(IndicatorValue::Crossover("bullish".to_string(), macd)))
+        } else if crossed_down {
+            Ok(Some(IndicatorValue::Crossover("bearish".to_string(), macd)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Seeds with the SMA of the first `period` values, then applies the
+/// standard `2/(period+1)` EMA multiplier for the rest.
+fn ema_series(prices: &[f64], period: usize) -> Vec<f64> {
+    let mut out = vec![f64::NAN; prices.len()];
+    if prices.len() < period {
+        return out;
+    }
+    let seed: f64 = prices[..period].iter().sum::<f64>() / period as f64;
+    out[period - 1] = seed;
+    let multiplier = 2.0 / (period as f64 + 1.0);
+    for i in period..prices.len() {
+        out[i] = (prices[i] - out[i - 1]) * multiplier + out[i - 1];
+    }
+    out
+}
+
+impl TechnicalIndicator for MACD {
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String> {
+        let series = self.series(prices)?;
+        let last = *series.last().ok_or("no data")?;
+        Ok(IndicatorValue::Vector(last.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_constructor_uses_standard_periods() {
+        let macd = MACD::new(12, 26, 9);
+        assert_eq!((macd.fast, macd.slow, macd.signal), (12, 26, 9));
+    }
+
+    #[test]
+    fn produces_a_value_once_warmed_up() {
+        let prices: Vec<f64> = (0..40).map(|i| 100.0 + (i as f64 * 0.5).sin() * 5.0 + i as f64 * 0.1).collect();
+        let macd = MACD::new(12, 26, 9);
+        let value = macd.calculate(&prices).unwrap();
+        match value {
+            IndicatorValue::Vector(v) => assert_eq!(v.len(), 3),
+            other => panic!("expected Vector, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn detects_a_crossover_on_a_known_series() {
+        // A series that dips then rallies hard should produce a bullish
+        // MACD/signal crossover somewhere during the rally.
+        let mut prices: Vec<f64> = (0..30).map(|_| 100.0).collect();
+        prices.extend((0..20).map(|i| 100.0 - i as f64));
+        prices.extend((0..20).map(|i| 80.0 + i as f64 * 3.0));
+
+        let macd = MACD::new(3, 6, 3);
+        let found_bullish = (1..=prices.len()).any(|len| {
+            matches!(
+                macd.crossover(&prices[..len]),
+                Ok(Some(IndicatorValue::Crossover(ref k, _))) if k == "bullish"
+            )
+        });
+        assert!(found_bullish, "expected a bullish crossover during the rally");
+    }
+
+    #[test]
+    fn rejects_fast_not_shorter_than_slow() {
+        let macd = MACD::new(26, 12, 9);
+        assert!(macd.calculate(&vec![1.0; 50]).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Technical indicators used by the screener and the strategy layer.
+
+pub mod bollinger;
+pub mod delta;
+pub mod macd;
+pub mod rsi;
+pub mod sma;
+
+pub use bollinger::BollingerBands;
+pub use delta::DeltaCalculator;
+pub use macd::MACD;
+pub use rsi::RSI;
+pub use sma::{EMA, SMA};
+
+/// The result of evaluating a [`TechnicalIndicator`] on a price series.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndicatorValue {
+    /// A single current value, e.g. the latest RSI reading.
+    Scalar(f64),
+    /// Several related values, e.g. MACD line/signal/histogram or
+    /// Bollinger upper/middle/lower.
+    Vector(Vec<f64>),
+    /// A named crossover event and the price/level it crossed at.
+    Crossover(String, f64),
+}
+
+impl IndicatorValue {
+    /// Returns the scalar value, or `None` if this is a `Vector`/`Crossover`.
+    pub fn as_scalar(&self) -> Option<f64> {
+        match self {
+            IndicatorValue::Scalar(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the vector of values, or `None` if this is a `Scalar`/`Crossover`.
+    pub fn as_vector(&self) -> Option<&[f64]> {
+        match self {
+            IndicatorValue::Vector(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns `(label, level)`, or `None` if this isn't a `Crossover`.
+    pub fn as_crossover(&self) -> Option<(&str, f64)> {
+        match self {
+            IndicatorValue::Crossover(label, level) => Some((label.as_str(), *level)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for IndicatorValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndicatorValue::Scalar(v) => write!(f, "{v:.4}"),
+            IndicatorValue::Vector(values) => {
+                let parts: Vec<String> = values.iter().map(|v| format!("{v:.4}")).collect();
+                write!(f, "[{}]", parts.join(", "))
+            }
+            IndicatorValue::Crossover(label, level) => write!(f, "{label}@{level:.4}"),
+        }
+    }
+}
+
+/// A technical indicator computed from a closing-price series.
+pub trait TechnicalIndicator {
+    /// Computes the indicator's latest value from `prices` (oldest first).
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_round_trips() {
+        let value = IndicatorValue::Scalar(42.0);
+        assert_eq!(value, IndicatorValue::Scalar(42.0));
+    }
+
+    #[test]
+    fn as_scalar_extracts_only_scalar_variants() {
+        assert_eq!(IndicatorValue::Scalar(1.5).as_scalar(), Some(1.5));
+        assert_eq!(IndicatorValue::Vector(vec![1.0]).as_scalar(), None);
+        assert_eq!(IndicatorValue::Crossover("x".into(), 1.0).as_scalar(), None);
+    }
+
+    #[test]
+    fn as_vector_extracts_only_vector_variants() {
+        assert_eq!(IndicatorValue::Vector(vec![1.0, 2.0]).as_vector(), Some(&[1.0, 2.0][..]));
+        assert_eq!(IndicatorValue::Scalar(1.0).as_vector(), None);
+    }
+
+    #[test]
+    fn as_crossover_extracts_only_crossover_variants() {
+        assert_eq!(
+            IndicatorValue::Crossover("bullish".into(), 10.0).as_crossover(),
+            Some(("bullish", 10.0))
+        );
+        assert_eq!(IndicatorValue::Scalar(1.0).as_crossover(), None);
+    }
+
+    #[test]
+    fn display_prints_compact_human_readable_forms() {
+        assert_eq!(IndicatorValue::Scalar(42.0).to_string(), "42.0000");
+        assert_eq!(
+            IndicatorValue::Vector(vec![1.0, 2.0]).to_string(),
+            "[1.0000, 2.0000]"
+        );
+        assert_eq!(
+            IndicatorValue::Crossover("bullish".into(), 10.0).to_string(),
+            "bullish@10.0000"
+        );
+    }
+}
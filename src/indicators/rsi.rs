@@ -0,0 +1,123 @@
+use super::{IndicatorValue, TechnicalIndicator};
+
+/// Wilder's smoothed Relative Strength Index.
+pub struct RSI {
+    pub period: usize,
+}
+
+impl RSI {
+    pub fn new(period: usize) -> Self {
+        RSI { period }
+    }
+
+    /// Returns one RSI value per input bar. The warm-up region (the first
+    /// `period` bars, before a full `period` of price changes exists) is filled
+    /// with `f64::NAN` so the series length always matches `prices.len()`
+    /// and the frontend can zip it directly with timestamps. An `avg_loss`
+    /// of zero yields `100.0` rather than `NaN`.
+    pub fn calculate_series(&self, prices: &[f64]) -> Result<Vec<f64>, String> {
+        if self.period == 0 {
+            return Err("RSI period must be non-zero".to_string());
+        }
+        if prices.len() < self.period + 1 {
+            return Err(format!(
+                "need at least {} prices for a period-{} RSI, got {}",
+                self.period + 1,
+                self.period,
+                prices.len()
+            ));
+        }
+
+        let mut series = vec![f64::NAN; prices.len()];
+
+        let mut avg_gain = 0.0;
+        let mut avg_loss = 0.0;
+        for i in 1..=self.period {
+            let change = prices[i] - prices[i - 1];
+            if change >= 0.0 {
+                avg_gain += change;
+            } else {
+                avg_loss += -change;
+            }
+        }
+        avg_gain /= self.period as f64;
+        avg_loss /= self.period as f64;
+        series[self.period] = rsi_from_averages(avg_gain, avg_loss);
+
+        for i in (self.period + 1)..prices.len() {
+            let change = prices[i] - prices[i - 1];
+            let (gain, loss) = if change >= 0.0 { (change, 0.0) } else { (0.0, -change) };
+            avg_gain = (avg_gain * (self.period - 1) as f64 + gain) / self.period as f64;
+            avg_loss = (avg_loss * (self.period - 1) as f64 + loss) / self.period as f64;
+            series[i] = rsi_from_averages(avg_gain, avg_loss);
+        }
+
+        Ok(series)
+    }
+}
+
+fn rsi_from_averages(avg_gain: f64, avg_loss: f64) -> f64 {
+    if avg_loss == 0.0 {
+        return 100.0;
+    }
+    let rs = avg_gain / avg_loss;
+    100.0 - 100.0 / (1.0 + rs)
+}
+
+impl TechnicalIndicator for RSI {
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String> {
+        let series = self.calculate_series(prices)?;
+        Ok(IndicatorValue::Scalar(*series.last().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_hand_computed_series() {
+        // 15 prices: exactly the 14 changes a period-14 RSI warm-up needs.
+        let prices = vec![
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03,
+            45.61, 46.28, 46.28,
+        ];
+        let rsi = RSI::new(14);
+        let series = rsi.calculate_series(&prices).unwrap();
+        let value = *series.last().unwrap();
+        // Hand-computed via the standard Wilder warm-up: avg gain/loss over
+        // the 14 changes, with no smoothing step after it.
+        assert!((value - 70.53).abs() < 0.5, "unexpected RSI: {value}");
+    }
+
+    #[test]
+    fn zero_average_loss_yields_100_not_nan() {
+        let prices: Vec<f64> = (0..16).map(|i| 100.0 + i as f64).collect();
+        let rsi = RSI::new(14);
+        let value = *rsi.calculate_series(&prices).unwrap().last().unwrap();
+        assert_eq!(value, 100.0);
+    }
+
+    #[test]
+    fn errors_with_too_few_prices() {
+        let rsi = RSI::new(14);
+        assert!(rsi.calculate_series(&[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn exactly_period_plus_one_prices_returns_a_value() {
+        let prices: Vec<f64> = (0..15).map(|i| 100.0 + (i % 3) as f64).collect();
+        let rsi = RSI::new(14);
+        assert!(rsi.calculate_series(&prices).is_ok());
+    }
+
+    #[test]
+    fn series_length_and_warmup_region_match_input() {
+        let prices: Vec<f64> = (0..20).map(|i| 100.0 + (i % 5) as f64).collect();
+        let rsi = RSI::new(14);
+        let series = rsi.calculate_series(&prices).unwrap();
+        assert_eq!(series.len(), prices.len());
+        assert!(series[..14].iter().all(|v| v.is_nan()));
+        assert!(series[14..].iter().all(|v| !v.is_nan()));
+    }
+}
@@ -0,0 +1,104 @@
+use super::{IndicatorValue, TechnicalIndicator};
+
+/// Simple moving average of the last `period` prices.
+pub struct SMA {
+    pub period: usize,
+}
+
+impl SMA {
+    pub fn new(period: usize) -> Self {
+        SMA { period }
+    }
+}
+
+impl TechnicalIndicator for SMA {
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String> {
+        if self.period == 0 {
+            return Err("SMA period must be non-zero".to_string());
+        }
+        if prices.len() < self.period {
+            return Err(format!(
+                "need at least {} prices for a period-{} SMA, got {}",
+                self.period,
+                self.period,
+                prices.len()
+            ));
+        }
+        let window = &prices[prices.len() - self.period..];
+        let avg = window.iter().sum::<f64>() / self.period as f64;
+        Ok(IndicatorValue::Scalar(avg))
+    }
+}
+
+/// Exponential moving average, seeded with the SMA of the first `period`
+/// values and then smoothed with the standard `2/(period+1)` multiplier.
+pub struct EMA {
+    pub period: usize,
+}
+
+impl EMA {
+    pub fn new(period: usize) -> Self {
+        EMA { period }
+    }
+
+    /// Returns the EMA series aligned with `prices`; the first `period - 1`
+    /// entries are `f64::NAN` (no seed yet).
+    pub fn calculate_series(&self, prices: &[f64]) -> Result<Vec<f64>, String> {
+        if self.period == 0 {
+            return Err("EMA period must be non-zero".to_string());
+        }
+        if prices.len() < self.period {
+            return Err(format!(
+                "need at least {} prices for a period-{} EMA, got {}",
+                self.period,
+                self.period,
+                prices.len()
+            ));
+        }
+
+        let mut out = vec![f64::NAN; prices.len()];
+        let seed = prices[..self.period].iter().sum::<f64>() / self.period as f64;
+        out[self.period - 1] = seed;
+        let multiplier = 2.0 / (self.period as f64 + 1.0);
+        for i in self.period..prices.len() {
+            out[i] = (prices[i] - out[i - 1]) * multiplier + out[i - 1];
+        }
+        Ok(out)
+    }
+}
+
+impl TechnicalIndicator for EMA {
+    fn calculate(&self, prices: &[f64]) -> Result<IndicatorValue, String> {
+        let series = self.calculate_series(prices)?;
+        Ok(IndicatorValue::Scalar(*series.last().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sma_averages_the_trailing_window() {
+        let sma = SMA::new(3);
+        let value = sma.calculate(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(value, IndicatorValue::Scalar(4.0));
+    }
+
+    #[test]
+    fn sma_errors_with_too_few_prices() {
+        let sma = SMA::new(5);
+        assert!(sma.calculate(&[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn ema_seeds_with_the_sma_of_the_first_window() {
+        let ema = EMA::new(3);
+        let series = ema.calculate_series(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert!(series[0].is_nan());
+        assert!(series[1].is_nan());
+        assert!((series[2] - 2.0).abs() < 1e-9);
+        // multiplier = 2/(3+1) = 0.5 -> (4 - 2) * 0.5 + 2 = 3.0
+        assert!((series[3] - 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,20 @@
+//! A multi-venue crypto market-data and backtesting engine: websocket
+//! collectors per exchange, a shared cross-venue state store, offline
+//! replay/backtesting, and the indicators/strategies built on top of them.
+//! There's no user-facing service layer here (no HTTP server, no session
+//! or token auth) — this crate is a library consumed by whatever drives
+//! it, not a standalone backend.
+
+pub mod adapters;
+pub mod analytics;
+pub mod api;
+pub mod backtest;
+pub mod base_classes;
+pub mod collectors;
+pub mod exchanges;
+pub mod indicators;
+pub mod models;
+pub mod parser;
+pub mod risk;
+pub mod screener;
+pub mod strategies;
@@ -0,0 +1,125 @@
+//! Canonical trade-side and tick types shared across collectors, the
+//! parser, and the backtest engine.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    #[default]
+    Buy,
+    Sell,
+}
+
+/// A single normalized trade tick, unscaled (`f64` price/qty) regardless of
+/// which venue or storage format it came from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeTick {
+    pub ts_ns: i64,
+    pub price: f64,
+    pub size: f64,
+    pub side: Side,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+}
+
+/// A venue's fixed-point scale for raw trades, and the single place that
+/// turns a raw scaled trade into the canonical [`TradeTick`] both the live
+/// engine's collectors and the backtest loaders should build from. Each
+/// venue previously rounded its own price/qty scale ad hoc; implementors
+/// just declare their scale and get `normalize_trade` for free.
+pub trait Exchange {
+    /// Divisor applied to this venue's raw integer trade prices.
+    const PRICE_SCALE: i64;
+    /// Divisor applied to this venue's raw integer trade sizes.
+    const QTY_SCALE: i64;
+
+    fn normalize_trade(price_scaled: i64, size_scaled: i64, side: Side, ts_ns: i64) -> TradeTick {
+        normalize_trade_with_scale(price_scaled, size_scaled, Self::PRICE_SCALE, Self::QTY_SCALE, side, ts_ns)
+    }
+}
+
+/// Turns a raw scaled trade into a canonical [`TradeTick`] using explicit
+/// price/qty scales rather than an [`Exchange`] implementor's fixed
+/// constants, for venues where the price scale varies per symbol (see
+/// [`PriceScaleRegistry`]).
+pub fn normalize_trade_with_scale(price_scaled: i64, size_scaled: i64, price_scale: i64, qty_scale: i64, side: Side, ts_ns: i64) -> TradeTick {
+    TradeTick {
+        ts_ns,
+        price: price_scaled as f64 / price_scale as f64,
+        size: size_scaled as f64 / qty_scale as f64,
+        side,
+        best_bid: None,
+        best_ask: None,
+    }
+}
+
+/// Derives the fixed-point scale an instrument's tick size needs to be
+/// stored as an exact integer, e.g. a `0.01` tick size needs a scale of
+/// `100`, while `0.00000001` needs `100_000_000`. A single per-exchange
+/// scale loses precision for coarse-ticked symbols and wastes range for
+/// fine-ticked ones, so this is resolved per symbol instead.
+pub fn scale_for_tick_size(tick_size: f64) -> i64 {
+    let mut scale: i64 = 1;
+    let mut value = tick_size.abs();
+    while (value - value.round()).abs() > 1e-9 && scale < 1_000_000_000_000 {
+        value *= 10.0;
+        scale *= 10;
+    }
+    scale
+}
+
+/// Per-symbol price scale, resolved from each instrument's tick size and
+/// looked up by collectors instead of assuming one scale fits every symbol
+/// on a venue.
+#[derive(Debug, Default)]
+pub struct PriceScaleRegistry {
+    scales: HashMap<String, i64>,
+}
+
+impl PriceScaleRegistry {
+    pub fn new() -> Self {
+        PriceScaleRegistry::default()
+    }
+
+    /// Registers `symbol`'s scale, derived from its instrument spec's tick
+    /// size.
+    pub fn register(&mut self, symbol: &str, tick_size: f64) {
+        self.scales.insert(symbol.to_string(), scale_for_tick_size(tick_size));
+    }
+
+    /// The registered scale for `symbol`, or `fallback` if `symbol` has no
+    /// registered tick size yet.
+    pub fn scale_for(&self, symbol: &str, fallback: i64) -> i64 {
+        *self.scales.get(symbol).unwrap_or(&fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_for_tick_size_resolves_coarse_and_fine_ticks_distinctly() {
+        assert_eq!(scale_for_tick_size(0.01), 100);
+        assert_eq!(scale_for_tick_size(0.00000001), 100_000_000);
+    }
+
+    #[test]
+    fn registry_resolves_distinct_scales_per_symbol() {
+        let mut registry = PriceScaleRegistry::new();
+        registry.register("BTC-USD", 0.01);
+        registry.register("SHIB-USD", 0.00000001);
+
+        assert_eq!(registry.scale_for("BTC-USD", 1), 100);
+        assert_eq!(registry.scale_for("SHIB-USD", 1), 100_000_000);
+    }
+
+    #[test]
+    fn unregistered_symbol_falls_back_to_the_given_scale() {
+        let registry = PriceScaleRegistry::new();
+        assert_eq!(registry.scale_for("UNKNOWN-USD", 100_000_000), 100_000_000);
+    }
+}
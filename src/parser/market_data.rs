@@ -0,0 +1,47 @@
+//! Streaming (multi-line) market-data parsing: the batch companion to the
+//! single-record [`super::parse_market_data`].
+
+use std::io::BufRead;
+
+use super::{parse_market_data, ParsedData};
+
+/// Lazily parses one [`ParsedData`] per non-blank line of `reader`, without
+/// loading the whole file into memory. Errors carry the 1-based line number
+/// so a bad row can be located.
+pub fn parse_market_data_lines(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<ParsedData, String>> {
+    reader.lines().enumerate().filter_map(|(i, line)| {
+        let line_no = i + 1;
+        match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(parse_market_data(&line).map_err(|e| format!("line {line_no}: {e}"))),
+            Err(e) => Some(Err(format!("line {line_no}: failed to read: {e}"))),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn yields_one_record_per_non_blank_line() {
+        let input = "1000,BTCUSDT,50000.5,1.25\n\n2000,ETHUSDT,3000.0,2.0\n";
+        let records: Vec<_> = parse_market_data_lines(Cursor::new(input)).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].as_ref().unwrap().symbol, "BTCUSDT");
+        assert_eq!(records[1].as_ref().unwrap().symbol, "ETHUSDT");
+    }
+
+    #[test]
+    fn error_messages_carry_the_line_number() {
+        let input = "1000,BTCUSDT,50000.5,1.25\nnot,a,valid,line,here\n";
+        let records: Vec<_> = parse_market_data_lines(Cursor::new(input)).collect();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].is_ok());
+        let err = records[1].as_ref().unwrap_err();
+        assert!(err.starts_with("line 2:"), "unexpected error: {err}");
+    }
+}
@@ -0,0 +1,93 @@
+//! Parses recorded/streamed market-data records in either JSON or CSV form.
+
+pub mod market_data;
+pub mod price_parser;
+
+use serde::Deserialize;
+
+/// A single parsed market-data record.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ParsedData {
+    pub ts: i64,
+    pub symbol: String,
+    pub price: f64,
+    pub volume: f64,
+}
+
+/// Parses one line of market data, auto-detecting the format by its first
+/// non-whitespace character: `{` means JSON (`{"ts":...,"symbol":...,
+/// "price":...,"volume":...}`), anything else is treated as the CSV
+/// fallback `ts,symbol,price,volume`.
+pub fn parse_market_data(line: &str) -> Result<ParsedData, String> {
+    let trimmed = line.trim();
+    match trimmed.chars().next() {
+        Some('{') => serde_json::from_str(trimmed).map_err(|e| format!("invalid JSON market data: {e}")),
+        Some(_) => parse_csv_record(trimmed),
+        None => Err("empty market data line".to_string()),
+    }
+}
+
+fn parse_csv_record(line: &str) -> Result<ParsedData, String> {
+    let fields: Vec<&str> = line.split(',').collect();
+    if fields.len() != 4 {
+        return Err(format!(
+            "expected 4 CSV fields (ts,symbol,price,volume), got {}: {line:?}",
+            fields.len()
+        ));
+    }
+    let ts = fields[0]
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| format!("invalid ts {:?}: {e}", fields[0]))?;
+    let symbol = fields[1].trim().to_string();
+    let price = fields[2]
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| format!("invalid price {:?}: {e}", fields[2]))?;
+    let volume = fields[3]
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| format!("invalid volume {:?}: {e}", fields[3]))?;
+    Ok(ParsedData { ts, symbol, price, volume })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_record() {
+        let line = r#"{"ts":1000,"symbol":"BTCUSDT","price":50000.5,"volume":1.25}"#;
+        let parsed = parse_market_data(line).unwrap();
+        assert_eq!(
+            parsed,
+            ParsedData {
+                ts: 1000,
+                symbol: "BTCUSDT".to_string(),
+                price: 50000.5,
+                volume: 1.25,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_csv_record() {
+        let parsed = parse_market_data("1000,BTCUSDT,50000.5,1.25").unwrap();
+        assert_eq!(
+            parsed,
+            ParsedData {
+                ts: 1000,
+                symbol: "BTCUSDT".to_string(),
+                price: 50000.5,
+                volume: 1.25,
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_input_returns_a_descriptive_error_instead_of_panicking() {
+        assert!(parse_market_data("not,enough").is_err());
+        assert!(parse_market_data(r#"{"ts": "oops"}"#).is_err());
+        assert!(parse_market_data("").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! Fixed-point price/tick-size parsing: converts a price string straight
+//! into the integer-scaled representation used by [`crate::backtest::bin_format`]
+//! and the collectors, avoiding the rounding error an `f64` round-trip would
+//! introduce for low-priced coins.
+
+/// Parses `price_str` into an integer scaled by `10^scale`, e.g. with
+/// `scale = 8`, `"0.00001234"` becomes `1234`. Returns `None` if the string
+/// isn't a valid decimal number.
+pub fn parse_scaled(price_str: &str, scale: u32) -> Option<i64> {
+    let price_str = price_str.trim();
+    let (sign, unsigned) = match price_str.strip_prefix('-') {
+        Some(rest) => (-1i64, rest),
+        None => (1i64, price_str),
+    };
+    if unsigned.is_empty() {
+        return None;
+    }
+
+    let (int_part, frac_part) = match unsigned.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (unsigned, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part.chars().all(|c| c.is_ascii_digit()) || !frac_part.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let int_value: i64 = if int_part.is_empty() { 0 } else { int_part.parse().ok()? };
+
+    let scale = scale as usize;
+    let frac_digits = if frac_part.len() > scale {
+        // Truncate rather than round, matching exchange tick-size semantics:
+        // anything finer than the scale isn't representable and is dropped.
+        &frac_part[..scale]
+    } else {
+        frac_part
+    };
+    let padded: String = format!("{frac_digits:0<scale$}");
+    let frac_value: i64 = if padded.is_empty() { 0 } else { padded.parse().ok()? };
+
+    let unit = 10i64.checked_pow(scale as u32)?;
+    Some(sign * (int_value.checked_mul(unit)?.checked_add(frac_value)?))
+}
+
+/// Formats a scaled integer (as produced by [`parse_scaled`]) back into a
+/// decimal string with exactly `scale` fractional digits, without the
+/// floating-point error an `f64` formatting round-trip could introduce.
+pub fn format_scaled(value: i64, scale: u32) -> String {
+    let scale = scale as usize;
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let sign = if value < 0 { "-" } else { "" };
+    let magnitude = value.unsigned_abs();
+    let unit = 10u64.pow(scale as u32);
+    let int_part = magnitude / unit;
+    let frac_part = magnitude % unit;
+    format!("{sign}{int_part}.{frac_part:0>scale$}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_low_priced_coin_with_many_decimal_places() {
+        assert_eq!(parse_scaled("0.00001234", 8), Some(1234));
+    }
+
+    #[test]
+    fn parses_integers_and_negatives() {
+        assert_eq!(parse_scaled("50000", 8), Some(5_000_000_000_000));
+        assert_eq!(parse_scaled("-1.5", 2), Some(-150));
+    }
+
+    #[test]
+    fn truncates_precision_finer_than_the_scale() {
+        // The 9th digit is beyond an 8-decimal scale and is dropped, not rounded.
+        assert_eq!(parse_scaled("0.123456789", 8), Some(12_345_678));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(parse_scaled("", 8), None);
+        assert_eq!(parse_scaled("abc", 8), None);
+        assert_eq!(parse_scaled("1.2.3", 8), None);
+    }
+
+    #[test]
+    fn format_scaled_round_trips_parse_scaled() {
+        let scaled = parse_scaled("0.00001234", 8).unwrap();
+        assert_eq!(format_scaled(scaled, 8), "0.00001234");
+        assert_eq!(format_scaled(-150, 2), "-1.50");
+    }
+}
@@ -0,0 +1,287 @@
+//! Account-wide kill switch, independent of any single strategy's own
+//! risk checks: halts trading once the day's realized loss or the running
+//! drawdown from peak equity breaches a configured limit.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// What a [`GlobalRiskManager::evaluate`] call tells the caller to do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskAction {
+    Continue,
+    HaltTrading,
+    /// Close every open position at market, immediately. More urgent than
+    /// [`RiskAction::HaltTrading`], which only stops new orders.
+    FlattenAll,
+}
+
+/// Recent equity samples, oldest first, the input to
+/// [`GlobalRiskManager::should_panic_sell`]'s rapid-drawdown check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketState {
+    pub equity_history: Vec<(DateTime<Utc>, f64)>,
+}
+
+/// One currently-open position, the input to [`GlobalRiskManager::can_open`]'s
+/// concentration checks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub symbol: String,
+    pub size: f64,
+}
+
+/// Halts trading once either the day's realized loss or the drawdown from
+/// peak equity crosses a configured limit. The daily-loss baseline resets
+/// whenever [`GlobalRiskManager::evaluate`] is called with a `now` that has
+/// crossed into a new UTC day since the last call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalRiskManager {
+    pub max_daily_loss: f64,
+    pub max_drawdown_pct: f64,
+    day: Option<DateTime<Utc>>,
+    pnl_at_day_start: f64,
+    peak_equity: f64,
+    panic_sell: Option<(f64, Duration)>,
+    pub max_open_positions: Option<usize>,
+    pub max_exposure_per_symbol: Option<f64>,
+}
+
+impl GlobalRiskManager {
+    pub fn new(max_daily_loss: f64, max_drawdown_pct: f64) -> Self {
+        GlobalRiskManager {
+            max_daily_loss,
+            max_drawdown_pct,
+            day: None,
+            pnl_at_day_start: 0.0,
+            peak_equity: f64::MIN,
+            panic_sell: None,
+            max_open_positions: None,
+            max_exposure_per_symbol: None,
+        }
+    }
+
+    /// Arms the rapid-drawdown panic-sell check: [`GlobalRiskManager::should_panic_sell`]
+    /// returns `true` once equity has fallen `drawdown_pct` or more from its
+    /// peak within the trailing `window`.
+    pub fn with_panic_sell(mut self, drawdown_pct: f64, window: Duration) -> Self {
+        self.panic_sell = Some((drawdown_pct, window));
+        self
+    }
+
+    /// Caps the number of simultaneously open positions and the total size
+    /// held in any one symbol, enforced by [`GlobalRiskManager::can_open`].
+    pub fn with_concentration_limits(mut self, max_open_positions: usize, max_exposure_per_symbol: f64) -> Self {
+        self.max_open_positions = Some(max_open_positions);
+        self.max_exposure_per_symbol = Some(max_exposure_per_symbol);
+        self
+    }
+
+    /// `false` if opening a `size`-sized position in `symbol` would push the
+    /// position count past [`GlobalRiskManager::max_open_positions`] or
+    /// `symbol`'s total exposure past
+    /// [`GlobalRiskManager::max_exposure_per_symbol`]; `true` otherwise, or
+    /// if neither cap is configured.
+    pub fn can_open(&self, symbol: &str, size: f64, open_positions: &[Position]) -> bool {
+        if let Some(max_open_positions) = self.max_open_positions {
+            if open_positions.len() >= max_open_positions {
+                return false;
+            }
+        }
+        if let Some(max_exposure_per_symbol) = self.max_exposure_per_symbol {
+            let existing_exposure: f64 =
+                open_positions.iter().filter(|p| p.symbol == symbol).map(|p| p.size).sum();
+            if existing_exposure + size > max_exposure_per_symbol {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks `current_pnl` (cumulative realized P&L) and `equity` as of
+    /// `now` against the configured limits, first resetting the daily-loss
+    /// baseline if `now` falls on a later UTC date than the last call.
+    /// `market` feeds [`GlobalRiskManager::should_panic_sell`]: a tripped
+    /// panic-sell check takes priority over the daily-loss/drawdown halt
+    /// and returns [`RiskAction::FlattenAll`], since a fast crash calls for
+    /// closing positions outright rather than merely blocking new ones.
+    pub fn evaluate(&mut self, now: DateTime<Utc>, current_pnl: f64, equity: f64, market: &MarketState) -> RiskAction {
+        let is_new_day = self.day.map(|day| day.date_naive() != now.date_naive()).unwrap_or(true);
+        if is_new_day {
+            self.day = Some(now);
+            self.pnl_at_day_start = current_pnl;
+        }
+        self.peak_equity = self.peak_equity.max(equity);
+
+        if self.should_panic_sell(market) {
+            return RiskAction::FlattenAll;
+        }
+
+        let daily_loss = self.pnl_at_day_start - current_pnl;
+        let drawdown_pct = if self.peak_equity > 0.0 {
+            ((self.peak_equity - equity) / self.peak_equity).max(0.0)
+        } else {
+            0.0
+        };
+
+        if daily_loss >= self.max_daily_loss || drawdown_pct >= self.max_drawdown_pct {
+            RiskAction::HaltTrading
+        } else {
+            RiskAction::Continue
+        }
+    }
+
+    /// `true` if `market`'s most recent equity sample has fallen by the
+    /// configured panic-sell fraction or more from the highest equity seen
+    /// within the trailing panic-sell window. `false` if no panic-sell
+    /// threshold was configured via [`GlobalRiskManager::with_panic_sell`]
+    /// or `market` has fewer than two samples to compare.
+    pub fn should_panic_sell(&self, market: &MarketState) -> bool {
+        let Some((drawdown_pct, window)) = self.panic_sell else { return false };
+        let Some(&(latest_at, latest_equity)) = market.equity_history.last() else { return false };
+
+        let peak_in_window = market
+            .equity_history
+            .iter()
+            .filter(|(at, _)| latest_at.signed_duration_since(*at).to_std().unwrap_or(Duration::ZERO) <= window)
+            .map(|&(_, equity)| equity)
+            .fold(f64::MIN, f64::max);
+
+        if peak_in_window <= 0.0 {
+            return false;
+        }
+        (peak_in_window - latest_equity) / peak_in_window >= drawdown_pct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    /// A [`MarketState`] with no history, so [`GlobalRiskManager::should_panic_sell`]
+    /// never trips it — for tests exercising the daily-loss/drawdown checks
+    /// in isolation.
+    fn no_panic() -> MarketState {
+        MarketState { equity_history: Vec::new() }
+    }
+
+    #[test]
+    fn continues_while_within_every_threshold() {
+        let mut manager = GlobalRiskManager::new(1_000.0, 0.5);
+
+        assert_eq!(manager.evaluate(at(0), -100.0, 9_900.0, &no_panic()), RiskAction::Continue);
+    }
+
+    #[test]
+    fn halts_once_the_daily_loss_limit_is_breached() {
+        let mut manager = GlobalRiskManager::new(500.0, 1.0);
+
+        assert_eq!(manager.evaluate(at(0), 0.0, 10_000.0, &no_panic()), RiskAction::Continue);
+        assert_eq!(manager.evaluate(at(1), -500.0, 9_500.0, &no_panic()), RiskAction::HaltTrading);
+    }
+
+    #[test]
+    fn halts_once_the_drawdown_limit_is_breached() {
+        let mut manager = GlobalRiskManager::new(1_000_000.0, 0.1);
+
+        assert_eq!(manager.evaluate(at(0), 0.0, 10_000.0, &no_panic()), RiskAction::Continue);
+        assert_eq!(manager.evaluate(at(1), -1_500.0, 8_500.0, &no_panic()), RiskAction::HaltTrading);
+    }
+
+    #[test]
+    fn the_daily_loss_baseline_resets_at_utc_midnight() {
+        let mut manager = GlobalRiskManager::new(500.0, 1.0);
+
+        assert_eq!(manager.evaluate(at(0), 0.0, 10_000.0, &no_panic()), RiskAction::Continue);
+        assert_eq!(manager.evaluate(at(23), -500.0, 9_500.0, &no_panic()), RiskAction::HaltTrading);
+
+        let next_day = at(0) + chrono::Duration::days(1);
+        assert_eq!(manager.evaluate(next_day, -500.0, 9_500.0, &no_panic()), RiskAction::Continue);
+    }
+
+    #[test]
+    fn evaluate_returns_flatten_all_once_a_fast_equity_crash_trips_panic_sell() {
+        let mut manager = GlobalRiskManager::new(1_000_000.0, 1.0).with_panic_sell(0.1, Duration::from_secs(60));
+        let t0 = at(0);
+        let crash = MarketState {
+            equity_history: vec![
+                (t0, 10_000.0),
+                (t0 + chrono::Duration::seconds(10), 9_800.0),
+                (t0 + chrono::Duration::seconds(20), 8_800.0),
+            ],
+        };
+
+        // The crash alone wouldn't breach the (intentionally huge) daily-loss
+        // or drawdown limits, so this only passes if FlattenAll actually
+        // comes from the panic-sell check.
+        assert_eq!(manager.evaluate(t0 + chrono::Duration::seconds(20), -1_200.0, 8_800.0, &crash), RiskAction::FlattenAll);
+    }
+
+    #[test]
+    fn should_panic_sell_is_false_with_no_panic_sell_threshold_configured() {
+        let manager = GlobalRiskManager::new(1_000.0, 1.0);
+        let market = MarketState { equity_history: vec![(at(0), 10_000.0), (at(0), 5_000.0)] };
+
+        assert!(!manager.should_panic_sell(&market));
+    }
+
+    #[test]
+    fn should_panic_sell_trips_on_a_fast_equity_crash_within_the_window() {
+        let manager = GlobalRiskManager::new(1_000_000.0, 1.0).with_panic_sell(0.1, Duration::from_secs(60));
+        let t0 = at(0);
+        let market = MarketState {
+            equity_history: vec![
+                (t0, 10_000.0),
+                (t0 + chrono::Duration::seconds(10), 9_800.0),
+                (t0 + chrono::Duration::seconds(20), 8_800.0),
+            ],
+        };
+
+        assert!(manager.should_panic_sell(&market));
+    }
+
+    #[test]
+    fn should_panic_sell_ignores_a_slow_decline_outside_the_window() {
+        let manager = GlobalRiskManager::new(1_000_000.0, 1.0).with_panic_sell(0.1, Duration::from_secs(60));
+        let t0 = at(0);
+        let market = MarketState {
+            equity_history: vec![
+                (t0, 10_000.0),
+                (t0 + chrono::Duration::minutes(30), 9_500.0),
+                (t0 + chrono::Duration::hours(2), 9_200.0),
+            ],
+        };
+
+        assert!(!manager.should_panic_sell(&market));
+    }
+
+    #[test]
+    fn can_open_allows_an_order_below_both_caps() {
+        let manager = GlobalRiskManager::new(1_000.0, 1.0).with_concentration_limits(3, 10.0);
+        let open = vec![Position { symbol: "BTC_USDT".to_string(), size: 2.0 }];
+
+        assert!(manager.can_open("ETH_USDT", 1.0, &open));
+    }
+
+    #[test]
+    fn can_open_rejects_an_order_at_the_position_count_cap() {
+        let manager = GlobalRiskManager::new(1_000.0, 1.0).with_concentration_limits(1, 10.0);
+        let open = vec![Position { symbol: "BTC_USDT".to_string(), size: 2.0 }];
+
+        assert!(!manager.can_open("ETH_USDT", 1.0, &open));
+    }
+
+    #[test]
+    fn can_open_rejects_an_order_that_would_exceed_per_symbol_exposure() {
+        let manager = GlobalRiskManager::new(1_000.0, 1.0).with_concentration_limits(10, 5.0);
+        let open = vec![Position { symbol: "BTC_USDT".to_string(), size: 4.0 }];
+
+        assert!(!manager.can_open("BTC_USDT", 2.0, &open));
+        assert!(manager.can_open("BTC_USDT", 1.0, &open));
+    }
+}
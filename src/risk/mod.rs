@@ -0,0 +1,151 @@
+//! Gates a strategy's promotion from paper to live trading on its recorded
+//! paper-trading performance, so promotion is a checked decision rather
+//! than a manual call.
+
+pub mod global;
+pub mod session;
+
+use crate::analytics::performance::RollingPerformance;
+
+/// Whether a strategy's paper-trading [`RollingPerformance`] clears the
+/// bar for live promotion, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromotionDecision {
+    pub eligible: bool,
+    pub reason: String,
+}
+
+/// Minimum paper-trading performance a strategy must clear before
+/// [`PromotionGate::evaluate`] considers it eligible for live trading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PromotionGate {
+    pub min_trades: usize,
+    pub min_sharpe: f64,
+    pub max_drawdown: f64,
+}
+
+impl PromotionGate {
+    /// Checks `perf` against every threshold in turn, stopping at (and
+    /// reporting) the first one it fails.
+    pub fn evaluate(&self, perf: &RollingPerformance) -> PromotionDecision {
+        if perf.trade_count() < self.min_trades {
+            return PromotionDecision {
+                eligible: false,
+                reason: format!(
+                    "only {} trades recorded, need at least {}",
+                    perf.trade_count(),
+                    self.min_trades
+                ),
+            };
+        }
+        if perf.sharpe_ratio() < self.min_sharpe {
+            return PromotionDecision {
+                eligible: false,
+                reason: format!(
+                    "Sharpe ratio {:.3} is below the minimum of {:.3}",
+                    perf.sharpe_ratio(),
+                    self.min_sharpe
+                ),
+            };
+        }
+        if perf.drawdown() > self.max_drawdown {
+            return PromotionDecision {
+                eligible: false,
+                reason: format!(
+                    "drawdown {:.3} exceeds the maximum of {:.3}",
+                    perf.drawdown(),
+                    self.max_drawdown
+                ),
+            };
+        }
+        PromotionDecision { eligible: true, reason: "meets every promotion threshold".to_string() }
+    }
+}
+
+/// Risk-per-trade position sizing: the size such that getting stopped out
+/// at `stop_price` loses exactly `risk_fraction * account_equity`, rather
+/// than sizing off a fixed fraction of the account regardless of how far
+/// away the stop is. Works for both long (`stop_price < entry_price`) and
+/// short (`stop_price > entry_price`) placements, since only the distance
+/// between entry and stop matters. Returns `0.0` if `entry_price` equals
+/// `stop_price`, since that stop distance can't be divided by.
+pub fn position_size(account_equity: f64, entry_price: f64, stop_price: f64, risk_fraction: f64) -> f64 {
+    let stop_distance = (entry_price - stop_price).abs();
+    if stop_distance == 0.0 {
+        return 0.0;
+    }
+    (account_equity * risk_fraction) / stop_distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn perf_with(pnls: &[f64]) -> RollingPerformance {
+        let mut perf = RollingPerformance::new(None, None);
+        let now = chrono::Utc::now();
+        for &pnl in pnls {
+            perf.on_trade_closed(now, pnl);
+        }
+        perf
+    }
+
+    #[test]
+    fn a_strategy_meeting_every_threshold_is_eligible() {
+        let perf = perf_with(&[10.0, 8.0, 12.0, 9.0, 11.0]);
+        let gate = PromotionGate { min_trades: 5, min_sharpe: 1.0, max_drawdown: 5.0 };
+
+        let decision = gate.evaluate(&perf);
+        assert!(decision.eligible);
+    }
+
+    #[test]
+    fn too_few_trades_is_reported_as_the_failing_reason() {
+        let perf = perf_with(&[10.0, 8.0]);
+        let gate = PromotionGate { min_trades: 5, min_sharpe: 0.0, max_drawdown: 100.0 };
+
+        let decision = gate.evaluate(&perf);
+        assert!(!decision.eligible);
+        assert!(decision.reason.contains("trades"), "unexpected reason: {}", decision.reason);
+    }
+
+    #[test]
+    fn a_sharpe_ratio_below_the_minimum_is_reported_as_the_failing_reason() {
+        let perf = perf_with(&[10.0, -9.0, 10.0, -9.0, 10.0]);
+        let gate = PromotionGate { min_trades: 5, min_sharpe: 5.0, max_drawdown: 100.0 };
+
+        let decision = gate.evaluate(&perf);
+        assert!(!decision.eligible);
+        assert!(decision.reason.contains("Sharpe"), "unexpected reason: {}", decision.reason);
+    }
+
+    #[test]
+    fn drawdown_beyond_the_maximum_is_reported_as_the_failing_reason() {
+        let perf = perf_with(&[10.0, -20.0, 5.0, 5.0, 5.0]);
+        let gate = PromotionGate { min_trades: 5, min_sharpe: -10.0, max_drawdown: 1.0 };
+
+        let decision = gate.evaluate(&perf);
+        assert!(!decision.eligible);
+        assert!(decision.reason.contains("drawdown"), "unexpected reason: {}", decision.reason);
+    }
+
+    #[test]
+    fn position_size_sizes_a_long_so_the_stop_loses_exactly_the_risked_fraction() {
+        let size = position_size(10_000.0, 100.0, 95.0, 0.01);
+
+        // Risking 1% of 10,000 equity ($100) over a $5 stop distance.
+        assert!((size - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn position_size_sizes_a_short_the_same_way_via_the_stop_distance() {
+        let size = position_size(10_000.0, 95.0, 100.0, 0.01);
+
+        assert!((size - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn position_size_is_zero_when_entry_equals_stop() {
+        assert_eq!(position_size(10_000.0, 100.0, 100.0, 0.01), 0.0);
+    }
+}
@@ -0,0 +1,89 @@
+//! Gates trading to configured UTC time-of-day windows, e.g. to sit out
+//! low-liquidity overnight hours regardless of calendar date.
+
+use chrono::{DateTime, NaiveTime, Utc};
+
+/// What a [`SessionManager::evaluate`] call tells the caller to do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionAction {
+    Allow,
+    Block,
+}
+
+/// Allows trading only within a configured set of UTC time-of-day windows.
+/// A window whose end is earlier than its start wraps past midnight, e.g.
+/// `(22:00, 02:00)` covers 22:00 through 23:59:59 and 00:00 up to, but not
+/// including, 02:00. Every window includes its start and excludes its end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionManager {
+    windows: Vec<(NaiveTime, NaiveTime)>,
+}
+
+impl SessionManager {
+    pub fn new(windows: Vec<(NaiveTime, NaiveTime)>) -> Self {
+        SessionManager { windows }
+    }
+
+    /// [`SessionAction::Allow`] if `now`'s UTC time-of-day falls inside any
+    /// configured window, [`SessionAction::Block`] otherwise. An empty
+    /// window list allows nothing.
+    pub fn evaluate(&self, now: DateTime<Utc>) -> SessionAction {
+        let time = now.time();
+        let in_any_window = self.windows.iter().any(|&(start, end)| {
+            if start <= end {
+                time >= start && time < end
+            } else {
+                time >= start || time < end
+            }
+        });
+        if in_any_window {
+            SessionAction::Allow
+        } else {
+            SessionAction::Block
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, hour, minute, 0).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn allows_trading_inside_a_plain_window() {
+        let manager = SessionManager::new(vec![(time(9, 0), time(17, 0))]);
+
+        assert_eq!(manager.evaluate(at(12, 0)), SessionAction::Allow);
+    }
+
+    #[test]
+    fn blocks_trading_outside_every_window() {
+        let manager = SessionManager::new(vec![(time(9, 0), time(17, 0))]);
+
+        assert_eq!(manager.evaluate(at(20, 0)), SessionAction::Block);
+    }
+
+    #[test]
+    fn a_window_wrapping_past_midnight_allows_both_sides_of_the_date_boundary() {
+        let manager = SessionManager::new(vec![(time(22, 0), time(2, 0))]);
+
+        assert_eq!(manager.evaluate(at(23, 30)), SessionAction::Allow);
+        assert_eq!(manager.evaluate(at(1, 0)), SessionAction::Allow);
+        assert_eq!(manager.evaluate(at(12, 0)), SessionAction::Block);
+    }
+
+    #[test]
+    fn no_configured_windows_blocks_everything() {
+        let manager = SessionManager::new(vec![]);
+
+        assert_eq!(manager.evaluate(at(12, 0)), SessionAction::Block);
+    }
+}
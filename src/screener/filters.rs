@@ -0,0 +1,162 @@
+//! Concrete [`Filter`] implementations for the [`Screener`](super::Screener).
+
+use super::{CryptoPair, Filter};
+use crate::indicators::sma::SMA;
+use crate::indicators::TechnicalIndicator;
+
+/// Passes when a pair's fast SMA is above its slow SMA, i.e. the price
+/// history shows a bullish crossover. Requires at least `slow` points of
+/// `price_history`; pairs without enough history fail the filter rather
+/// than erroring, since a screener runs over a mixed universe where not
+/// every symbol has deep history yet.
+pub struct SmaCrossFilter {
+    pub fast: usize,
+    pub slow: usize,
+}
+
+impl Filter for SmaCrossFilter {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        if pair.price_history.len() < self.slow {
+            return false;
+        }
+        let fast = SMA::new(self.fast).calculate(&pair.price_history);
+        let slow = SMA::new(self.slow).calculate(&pair.price_history);
+        match (fast, slow) {
+            (Ok(fast), Ok(slow)) => fast.as_scalar().unwrap() > slow.as_scalar().unwrap(),
+            _ => false,
+        }
+    }
+}
+
+/// Passes pairs with at least `min_volume_24h` traded over the last 24h.
+pub struct VolumeFilter {
+    pub min_volume_24h: f64,
+}
+
+impl Filter for VolumeFilter {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        pair.volume_24h >= self.min_volume_24h
+    }
+}
+
+/// Passes pairs whose 24h change falls within `[min_change_24h,
+/// max_change_24h]`.
+pub struct ChangeFilter {
+    pub min_change_24h: f64,
+    pub max_change_24h: f64,
+}
+
+impl Filter for ChangeFilter {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        pair.change_24h >= self.min_change_24h && pair.change_24h <= self.max_change_24h
+    }
+}
+
+/// Passes when any of `filters` passes, e.g. "volume high OR change high".
+pub struct AnyOf(pub Vec<Box<dyn Filter>>);
+
+impl Filter for AnyOf {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        self.0.iter().any(|filter| filter.check(pair))
+    }
+}
+
+/// Passes when every one of `filters` passes, the same semantics as
+/// [`Screener::scan`](super::Screener::scan)'s implicit AND, exposed as a
+/// combinator so it can be nested inside an [`AnyOf`]/[`Not`].
+pub struct AllOf(pub Vec<Box<dyn Filter>>);
+
+impl Filter for AllOf {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        self.0.iter().all(|filter| filter.check(pair))
+    }
+}
+
+/// Inverts `filter`, e.g. "NOT blacklisted".
+pub struct Not(pub Box<dyn Filter>);
+
+impl Filter for Not {
+    fn check(&self, pair: &CryptoPair) -> bool {
+        !self.0.check(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_rising_series_passes_the_crossover_filter() {
+        let pair = CryptoPair::new("BTC_USDT", 112.0)
+            .with_price_history(vec![100.0, 102.0, 104.0, 106.0, 108.0, 110.0, 112.0]);
+        let filter = SmaCrossFilter { fast: 2, slow: 5 };
+
+        assert!(filter.check(&pair));
+    }
+
+    #[test]
+    fn a_falling_series_fails_the_crossover_filter() {
+        let pair = CryptoPair::new("BTC_USDT", 88.0)
+            .with_price_history(vec![100.0, 98.0, 96.0, 94.0, 92.0, 90.0, 88.0]);
+        let filter = SmaCrossFilter { fast: 2, slow: 5 };
+
+        assert!(!filter.check(&pair));
+    }
+
+    #[test]
+    fn too_little_history_fails_the_filter_instead_of_erroring() {
+        let pair = CryptoPair::new("BTC_USDT", 100.0).with_price_history(vec![100.0, 102.0]);
+        let filter = SmaCrossFilter { fast: 2, slow: 5 };
+
+        assert!(!filter.check(&pair));
+    }
+
+    #[test]
+    fn volume_filter_rejects_pairs_below_the_minimum() {
+        let filter = VolumeFilter { min_volume_24h: 1000.0 };
+
+        assert!(filter.check(&CryptoPair::new("BTC_USDT", 100.0).with_volume_24h(1000.0)));
+        assert!(!filter.check(&CryptoPair::new("DOGE_USDT", 0.1).with_volume_24h(999.0)));
+    }
+
+    #[test]
+    fn change_filter_rejects_pairs_outside_the_band() {
+        let filter = ChangeFilter { min_change_24h: -5.0, max_change_24h: 20.0 };
+
+        assert!(filter.check(&CryptoPair::new("BTC_USDT", 100.0).with_change_24h(10.0)));
+        assert!(!filter.check(&CryptoPair::new("ETH_USDT", 100.0).with_change_24h(25.0)));
+        assert!(!filter.check(&CryptoPair::new("SOL_USDT", 100.0).with_change_24h(-6.0)));
+    }
+
+    #[test]
+    fn any_of_passes_if_at_least_one_inner_filter_passes() {
+        let filter = AnyOf(vec![
+            Box::new(VolumeFilter { min_volume_24h: 1000.0 }),
+            Box::new(ChangeFilter { min_change_24h: 10.0, max_change_24h: f64::MAX }),
+        ]);
+
+        // Fails volume, passes change.
+        assert!(filter.check(&CryptoPair::new("A", 1.0).with_volume_24h(0.0).with_change_24h(15.0)));
+        // Fails both.
+        assert!(!filter.check(&CryptoPair::new("B", 1.0).with_volume_24h(0.0).with_change_24h(1.0)));
+    }
+
+    #[test]
+    fn all_of_passes_only_if_every_inner_filter_passes() {
+        let filter = AllOf(vec![
+            Box::new(VolumeFilter { min_volume_24h: 1000.0 }),
+            Box::new(ChangeFilter { min_change_24h: 10.0, max_change_24h: f64::MAX }),
+        ]);
+
+        assert!(filter.check(&CryptoPair::new("A", 1.0).with_volume_24h(2000.0).with_change_24h(15.0)));
+        assert!(!filter.check(&CryptoPair::new("B", 1.0).with_volume_24h(0.0).with_change_24h(15.0)));
+    }
+
+    #[test]
+    fn not_inverts_the_inner_filter() {
+        let filter = Not(Box::new(VolumeFilter { min_volume_24h: 1000.0 }));
+
+        assert!(filter.check(&CryptoPair::new("A", 1.0).with_volume_24h(0.0)));
+        assert!(!filter.check(&CryptoPair::new("B", 1.0).with_volume_24h(2000.0)));
+    }
+}
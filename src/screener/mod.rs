@@ -0,0 +1,213 @@
+//! Filters a universe of [`CryptoPair`]s down to the ones worth a closer
+//! look, the same role [`crate::indicators`] plays for a single symbol's
+//! price series.
+
+pub mod filters;
+pub mod scanner;
+
+/// A snapshot of one symbol's market data, the unit the [`Screener`]
+/// filters operate on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CryptoPair {
+    pub symbol: String,
+    pub price: f64,
+    pub volume_24h: f64,
+    pub change_24h: f64,
+    /// Recent closing prices, oldest first, ending at `price`. Populated
+    /// lazily — filters that don't need history (e.g. volume/change
+    /// thresholds) work fine against an empty series.
+    pub price_history: Vec<f64>,
+}
+
+impl CryptoPair {
+    pub fn new(symbol: impl Into<String>, price: f64) -> Self {
+        CryptoPair {
+            symbol: symbol.into(),
+            price,
+            volume_24h: 0.0,
+            change_24h: 0.0,
+            price_history: Vec::new(),
+        }
+    }
+
+    pub fn with_price_history(mut self, history: Vec<f64>) -> Self {
+        self.price_history = history;
+        self
+    }
+
+    pub fn with_volume_24h(mut self, volume_24h: f64) -> Self {
+        self.volume_24h = volume_24h;
+        self
+    }
+
+    pub fn with_change_24h(mut self, change_24h: f64) -> Self {
+        self.change_24h = change_24h;
+        self
+    }
+}
+
+/// A predicate over a [`CryptoPair`], composable via the combinators in
+/// [`filters`].
+pub trait Filter {
+    fn check(&self, pair: &CryptoPair) -> bool;
+}
+
+/// Runs a universe of pairs through a set of filters, ANDed together.
+pub struct Screener {
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl Screener {
+    pub fn new() -> Self {
+        Screener { filters: Vec::new() }
+    }
+
+    pub fn with_filter(mut self, filter: Box<dyn Filter>) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Returns every pair that passes all configured filters, in the
+    /// order `pairs` was given.
+    pub fn scan(&self, pairs: &[CryptoPair]) -> Vec<CryptoPair> {
+        pairs
+            .iter()
+            .filter(|pair| self.filters.iter().all(|filter| filter.check(pair)))
+            .cloned()
+            .collect()
+    }
+
+    /// Like [`Screener::scan`], but sorts the surviving pairs by `key`,
+    /// highest first. Uses [`f64::total_cmp`] rather than `partial_cmp`, so
+    /// a `NaN` straight off an exchange feed (`key` reads `change_24h`/
+    /// `volume_24h`, neither validated by [`CryptoPair`]) sorts to one end
+    /// instead of panicking.
+    pub fn scan_ranked(&self, pairs: &[CryptoPair], key: RankKey) -> Vec<CryptoPair> {
+        let mut result = self.scan(pairs);
+        result.sort_by(|a, b| key.value(b).total_cmp(&key.value(a)));
+        result
+    }
+
+    /// Like [`Screener::scan_ranked`], but keeps only the top `n` pairs —
+    /// e.g. picking the `n` most liquid symbols to trade instead of a
+    /// hardcoded symbol list. `n` larger than the number of surviving pairs
+    /// just returns all of them.
+    pub fn top_n(&self, pairs: &[CryptoPair], n: usize, key: RankKey) -> Vec<CryptoPair> {
+        let mut ranked = self.scan_ranked(pairs, key);
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+/// What [`Screener::scan_ranked`] sorts by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankKey {
+    Change24h,
+    Volume24h,
+}
+
+impl RankKey {
+    fn value(&self, pair: &CryptoPair) -> f64 {
+        match self {
+            RankKey::Change24h => pair.change_24h,
+            RankKey::Volume24h => pair.volume_24h,
+        }
+    }
+}
+
+impl Default for Screener {
+    fn default() -> Self {
+        Screener::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct AboveVolume(f64);
+
+    impl Filter for AboveVolume {
+        fn check(&self, pair: &CryptoPair) -> bool {
+            pair.volume_24h >= self.0
+        }
+    }
+
+    #[test]
+    fn scan_keeps_only_pairs_that_pass_every_filter() {
+        let pairs = vec![
+            CryptoPair::new("BTC_USDT", 100.0).with_volume_24h(1000.0),
+            CryptoPair::new("DOGE_USDT", 0.1).with_volume_24h(10.0),
+        ];
+        let screener = Screener::new().with_filter(Box::new(AboveVolume(500.0)));
+
+        let result = screener.scan(&pairs);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].symbol, "BTC_USDT");
+    }
+
+    #[test]
+    fn scan_with_no_filters_returns_every_pair() {
+        let pairs = vec![CryptoPair::new("BTC_USDT", 100.0)];
+        let screener = Screener::new();
+
+        assert_eq!(screener.scan(&pairs), pairs);
+    }
+
+    #[test]
+    fn scan_ranked_sorts_survivors_by_the_chosen_key_descending() {
+        let pairs = vec![
+            CryptoPair::new("BTC_USDT", 100.0).with_change_24h(5.0),
+            CryptoPair::new("ETH_USDT", 100.0).with_change_24h(20.0),
+            CryptoPair::new("SOL_USDT", 100.0).with_change_24h(10.0),
+        ];
+        let screener = Screener::new();
+
+        let ranked = screener.scan_ranked(&pairs, RankKey::Change24h);
+
+        assert_eq!(
+            ranked.iter().map(|p| p.symbol.as_str()).collect::<Vec<_>>(),
+            vec!["ETH_USDT", "SOL_USDT", "BTC_USDT"]
+        );
+    }
+
+    #[test]
+    fn top_n_keeps_only_the_highest_ranked_survivors() {
+        let pairs = vec![
+            CryptoPair::new("BTC_USDT", 100.0).with_volume_24h(3000.0),
+            CryptoPair::new("ETH_USDT", 100.0).with_volume_24h(5000.0),
+            CryptoPair::new("SOL_USDT", 100.0).with_volume_24h(1000.0),
+            CryptoPair::new("DOGE_USDT", 100.0).with_volume_24h(4000.0),
+        ];
+        let screener = Screener::new();
+
+        let top = screener.top_n(&pairs, 2, RankKey::Volume24h);
+
+        assert_eq!(top.iter().map(|p| p.symbol.as_str()).collect::<Vec<_>>(), vec!["ETH_USDT", "DOGE_USDT"]);
+    }
+
+    #[test]
+    fn scan_ranked_does_not_panic_on_a_nan_key_from_an_unvalidated_feed() {
+        let pairs = vec![
+            CryptoPair::new("BTC_USDT", 100.0).with_change_24h(5.0),
+            CryptoPair::new("ETH_USDT", 100.0).with_change_24h(f64::NAN),
+            CryptoPair::new("SOL_USDT", 100.0).with_change_24h(10.0),
+        ];
+        let screener = Screener::new();
+
+        let ranked = screener.scan_ranked(&pairs, RankKey::Change24h);
+
+        assert_eq!(ranked.len(), 3);
+    }
+
+    #[test]
+    fn top_n_larger_than_the_survivor_count_returns_them_all() {
+        let pairs = vec![CryptoPair::new("BTC_USDT", 100.0).with_volume_24h(3000.0)];
+        let screener = Screener::new();
+
+        let top = screener.top_n(&pairs, 10, RankKey::Volume24h);
+
+        assert_eq!(top.len(), 1);
+    }
+}
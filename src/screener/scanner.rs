@@ -0,0 +1,113 @@
+//! Ties the [`crate::indicators`] and [`crate::screener`] modules together:
+//! computes a configured set of indicators per symbol and applies the
+//! [`Screener`](super::Screener) on top of them to produce ranked results.
+
+use std::collections::HashMap;
+
+use crate::indicators::{IndicatorValue, TechnicalIndicator};
+use crate::screener::{CryptoPair, Screener};
+
+/// One symbol's indicator readings and screener score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanResult {
+    pub symbol: String,
+    pub indicators: HashMap<String, IndicatorValue>,
+    pub score: f64,
+}
+
+/// Computes a named set of indicators per symbol against a caller-supplied
+/// price-history provider, then screens and scores the results.
+pub struct Scanner {
+    indicators: Vec<(String, Box<dyn TechnicalIndicator>)>,
+    screener: Screener,
+}
+
+impl Scanner {
+    pub fn new(screener: Screener) -> Self {
+        Scanner { indicators: Vec::new(), screener }
+    }
+
+    /// Registers an indicator under `name`, used as the key in each
+    /// [`ScanResult::indicators`] map.
+    pub fn with_indicator(mut self, name: impl Into<String>, indicator: Box<dyn TechnicalIndicator>) -> Self {
+        self.indicators.push((name.into(), indicator));
+        self
+    }
+
+    /// For each `symbol`, looks up its pair and price history via
+    /// `price_history`, runs every registered indicator, and — if the pair
+    /// passes the screener — emits a [`ScanResult`] scored by the pair's
+    /// `change_24h`. Results are sorted by score, highest first.
+    pub fn scan(
+        &self,
+        symbols: &[&str],
+        price_history: impl Fn(&str) -> Option<CryptoPair>,
+    ) -> Vec<ScanResult> {
+        let mut results = Vec::new();
+        for &symbol in symbols {
+            let Some(pair) = price_history(symbol) else { continue };
+            if !self.screener.scan(std::slice::from_ref(&pair)).iter().any(|p| p.symbol == pair.symbol) {
+                continue;
+            }
+
+            let mut indicators = HashMap::new();
+            for (name, indicator) in &self.indicators {
+                if let Ok(value) = indicator.calculate(&pair.price_history) {
+                    indicators.insert(name.clone(), value);
+                }
+            }
+
+            results.push(ScanResult { symbol: pair.symbol.clone(), indicators, score: pair.change_24h });
+        }
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::sma::SMA;
+    use crate::screener::filters::VolumeFilter;
+    use std::collections::HashMap as StdHashMap;
+
+    fn stub_pairs() -> StdHashMap<&'static str, CryptoPair> {
+        let mut pairs = StdHashMap::new();
+        pairs.insert(
+            "BTC_USDT",
+            CryptoPair::new("BTC_USDT", 112.0)
+                .with_volume_24h(5000.0)
+                .with_change_24h(8.0)
+                .with_price_history(vec![100.0, 102.0, 104.0, 106.0, 108.0, 110.0, 112.0]),
+        );
+        pairs.insert(
+            "DOGE_USDT",
+            CryptoPair::new("DOGE_USDT", 0.1).with_volume_24h(10.0).with_change_24h(1.0),
+        );
+        pairs
+    }
+
+    #[test]
+    fn scan_computes_indicators_and_scores_only_pairs_that_pass_the_screener() {
+        let pairs = stub_pairs();
+        let screener = Screener::new().with_filter(Box::new(VolumeFilter { min_volume_24h: 1000.0 }));
+        let scanner = Scanner::new(screener).with_indicator("sma_3", Box::new(SMA::new(3)));
+
+        let results = scanner.scan(&["BTC_USDT", "DOGE_USDT"], |symbol| pairs.get(symbol).cloned());
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].symbol, "BTC_USDT");
+        assert_eq!(results[0].score, 8.0);
+        assert_eq!(results[0].indicators.get("sma_3").unwrap().as_scalar(), Some(110.0));
+    }
+
+    #[test]
+    fn scan_skips_symbols_the_provider_has_no_data_for() {
+        let pairs = stub_pairs();
+        let scanner = Scanner::new(Screener::new());
+
+        let results = scanner.scan(&["UNKNOWN_USDT"], |symbol| pairs.get(symbol).cloned());
+
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! Exit-condition checking for an open position, shared by any strategy
+//! that holds inventory rather than only quoting (unlike
+//! [`crate::strategies::simple_quote`]).
+//!
+//! There's no `investor_demo.rs`/`HFTStrategy` in this crate — this is a
+//! from-scratch, tested home for the take-profit/stop-loss/timeout logic
+//! such a strategy would need, using [`crate::models::Side`] instead of a
+//! stringly-typed side so a typo like `"buyy"` fails to compile rather than
+//! silently falling through to the short-side branch.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::models::Side;
+
+/// Why [`PositionExit::check`] decided to close the position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExitSignal {
+    TakeProfit,
+    StopLoss,
+    MaxHoldTimeout,
+}
+
+/// The thresholds for an open position, checked against the current price
+/// and clock each tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionExit {
+    pub side: Side,
+    pub entry_price: f64,
+    /// Fractional gain (e.g. `0.02` for 2%) that triggers a take-profit
+    /// exit, measured favorably for `side`.
+    pub take_profit_pct: f64,
+    /// Fractional loss that triggers a stop-loss exit, measured
+    /// unfavorably for `side`.
+    pub stop_loss_pct: f64,
+    pub max_hold: Duration,
+    pub opened_at: DateTime<Utc>,
+}
+
+impl PositionExit {
+    /// Returns the first exit condition met, checked take-profit first,
+    /// then stop-loss, then the hold timeout. A long (`Buy`) position
+    /// profits as price rises above `entry_price` and is stopped out as it
+    /// falls below; a short (`Sell`) position is the mirror image.
+    pub fn check(&self, current_price: f64, now: DateTime<Utc>) -> Option<ExitSignal> {
+        let (take_profit_price, stop_loss_price) = match self.side {
+            Side::Buy => (
+                self.entry_price * (1.0 + self.take_profit_pct),
+                self.entry_price * (1.0 - self.stop_loss_pct),
+            ),
+            Side::Sell => (
+                self.entry_price * (1.0 - self.take_profit_pct),
+                self.entry_price * (1.0 + self.stop_loss_pct),
+            ),
+        };
+
+        let hit_take_profit = match self.side {
+            Side::Buy => current_price >= take_profit_price,
+            Side::Sell => current_price <= take_profit_price,
+        };
+        if hit_take_profit {
+            return Some(ExitSignal::TakeProfit);
+        }
+
+        let hit_stop_loss = match self.side {
+            Side::Buy => current_price <= stop_loss_price,
+            Side::Sell => current_price >= stop_loss_price,
+        };
+        if hit_stop_loss {
+            return Some(ExitSignal::StopLoss);
+        }
+
+        if now - self.opened_at >= self.max_hold {
+            return Some(ExitSignal::MaxHoldTimeout);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(side: Side) -> PositionExit {
+        PositionExit {
+            side,
+            entry_price: 100.0,
+            take_profit_pct: 0.05,
+            stop_loss_pct: 0.02,
+            max_hold: Duration::hours(1),
+            opened_at: DateTime::from_timestamp(0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn a_long_position_exits_at_take_profit_above_entry() {
+        let exit = position(Side::Buy);
+        assert_eq!(exit.check(105.0, exit.opened_at), Some(ExitSignal::TakeProfit));
+    }
+
+    #[test]
+    fn a_short_position_exits_at_the_mirrored_take_profit_below_entry() {
+        let exit = position(Side::Sell);
+        assert_eq!(exit.check(95.0, exit.opened_at), Some(ExitSignal::TakeProfit));
+    }
+
+    #[test]
+    fn a_short_position_exits_at_the_mirrored_stop_loss_above_entry() {
+        let exit = position(Side::Sell);
+        assert_eq!(exit.check(102.0, exit.opened_at), Some(ExitSignal::StopLoss));
+    }
+
+    #[test]
+    fn a_short_position_exits_at_the_max_hold_timeout() {
+        let exit = position(Side::Sell);
+        let later = exit.opened_at + Duration::hours(2);
+        assert_eq!(exit.check(100.0, later), Some(ExitSignal::MaxHoldTimeout));
+    }
+
+    #[test]
+    fn a_short_position_held_within_every_threshold_does_not_exit() {
+        let exit = position(Side::Sell);
+        let later = exit.opened_at + Duration::minutes(30);
+        assert_eq!(exit.check(100.5, later), None);
+    }
+}
@@ -0,0 +1,140 @@
+//! A basic symmetric-spread quoting strategy: maintain a ladder of bid/ask
+//! levels around the mid price.
+
+/// Which side of the book a [`QuoteLevel`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+/// One resting order the strategy wants live on the book.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuoteLevel {
+    pub side: Side,
+    pub price: f64,
+    pub size: f64,
+}
+
+/// The full set of orders `SimpleQuoteStrategy` wants live right now.
+#[derive(Debug, Clone, Default)]
+pub struct QuotePlan {
+    pub levels: Vec<QuoteLevel>,
+}
+
+/// The minimal set of actions needed to move the book from a previous
+/// [`QuotePlan`] to a new one: cancel what changed, place the replacements,
+/// and leave everything else alone.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuotePlanDiff {
+    pub to_cancel: Vec<QuoteLevel>,
+    pub to_place: Vec<QuoteLevel>,
+    pub unchanged: Vec<QuoteLevel>,
+}
+
+impl QuotePlan {
+    pub fn new(levels: Vec<QuoteLevel>) -> Self {
+        QuotePlan { levels }
+    }
+
+    /// Diffs this plan against `previous`, matching levels by `(side, price)`
+    /// so a level whose price and size are unchanged is left alone, a level
+    /// whose size changed (or that's new) is placed, and a `previous` level
+    /// with no exact `(side, price, size)` match in `self` is cancelled, so a
+    /// size change yields one cancel and one place.
+    pub fn diff(&self, previous: &QuotePlan) -> QuotePlanDiff {
+        let mut diff = QuotePlanDiff::default();
+
+        for level in &self.levels {
+            match previous
+                .levels
+                .iter()
+                .find(|p| p.side == level.side && p.price == level.price)
+            {
+                Some(prev) if prev.size == level.size => diff.unchanged.push(*level),
+                Some(_) => diff.to_place.push(*level),
+                None => diff.to_place.push(*level),
+            }
+        }
+
+        for prev in &previous.levels {
+            let still_wanted = self
+                .levels
+                .iter()
+                .any(|l| l.side == prev.side && l.price == prev.price && l.size == prev.size);
+            if !still_wanted {
+                diff.to_cancel.push(*prev);
+            }
+        }
+
+        diff
+    }
+}
+
+/// Produces a symmetric ladder of quotes around `mid`.
+pub struct SimpleQuoteStrategy {
+    pub spread_bps: f64,
+    pub levels: usize,
+    pub level_step_bps: f64,
+    pub size: f64,
+}
+
+impl SimpleQuoteStrategy {
+    pub fn new(spread_bps: f64, levels: usize, level_step_bps: f64, size: f64) -> Self {
+        SimpleQuoteStrategy {
+            spread_bps,
+            levels,
+            level_step_bps,
+            size,
+        }
+    }
+
+    /// Builds the desired [`QuotePlan`] for the given mid price.
+    pub fn quote(&self, mid: f64) -> QuotePlan {
+        let mut levels = Vec::with_capacity(self.levels * 2);
+        for i in 0..self.levels {
+            let offset_bps = self.spread_bps + (i as f64) * self.level_step_bps;
+            let offset = mid * offset_bps / 10_000.0;
+            levels.push(QuoteLevel {
+                side: Side::Bid,
+                price: mid - offset,
+                size: self.size,
+            });
+            levels.push(QuoteLevel {
+                side: Side::Ask,
+                price: mid + offset,
+                size: self.size,
+            });
+        }
+        QuotePlan::new(levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(side: Side, price: f64, size: f64) -> QuoteLevel {
+        QuoteLevel { side, price, size }
+    }
+
+    #[test]
+    fn identical_plan_yields_empty_diff() {
+        let plan = QuotePlan::new(vec![level(Side::Bid, 100.0, 1.0), level(Side::Ask, 101.0, 1.0)]);
+        let diff = plan.diff(&plan);
+        assert!(diff.to_cancel.is_empty());
+        assert!(diff.to_place.is_empty());
+        assert_eq!(diff.unchanged.len(), 2);
+    }
+
+    #[test]
+    fn single_changed_level_yields_one_cancel_and_one_place() {
+        let previous = QuotePlan::new(vec![level(Side::Bid, 100.0, 1.0), level(Side::Ask, 101.0, 1.0)]);
+        let next = QuotePlan::new(vec![level(Side::Bid, 100.0, 1.0), level(Side::Ask, 101.0, 2.0)]);
+
+        let diff = next.diff(&previous);
+        assert_eq!(diff.to_cancel, vec![level(Side::Ask, 101.0, 1.0)]);
+        assert_eq!(diff.to_place, vec![level(Side::Ask, 101.0, 2.0)]);
+        assert_eq!(diff.unchanged, vec![level(Side::Bid, 100.0, 1.0)]);
+    }
+}
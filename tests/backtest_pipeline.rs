@@ -0,0 +1,102 @@
+//! End-to-end test of the full backtest pipeline: write a synthetic `.bin`
+//! file, load it with `ReplayEngine`, and run it through `BacktestEngine`.
+
+use rust_trade::backtest::bin_format::{BinFileWriter, TradeRecord};
+use rust_trade::backtest::engine::{Adapter, BacktestEngine};
+use rust_trade::backtest::metrics::{Side, Trade};
+use rust_trade::backtest::replay::ReplayEngine;
+use rust_trade::models::{Side as TickSide, TradeTick};
+
+/// Closes a long trade on every Sell tick that follows a Buy tick.
+struct FlipFlopAdapter {
+    entry_price: Option<f64>,
+}
+
+impl Adapter for FlipFlopAdapter {
+    fn on_tick(&mut self, tick: &TradeTick) -> Option<Trade> {
+        match tick.side {
+            TickSide::Buy => {
+                self.entry_price = Some(tick.price);
+                None
+            }
+            TickSide::Sell => {
+                let entry_price = self.entry_price.take()?;
+                let now = rust_trade::backtest::engine::tick_time(tick.ts_ns);
+                Some(Trade {
+                    symbol: "BTCUSDT".into(),
+                    side: Side::Buy,
+                    entry_price,
+                    exit_price: tick.price,
+                    size: tick.size,
+                    pnl: (tick.price - entry_price) * tick.size,
+                    fees: 0.0,
+                    opened_at: now,
+                    closed_at: now,
+                    strategy_id: "flip_flop".into(),
+                })
+            }
+        }
+    }
+}
+
+fn scratch_path() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("rust-trade-pipeline-test-{}.bin", std::process::id()))
+}
+
+#[test]
+fn replays_a_bin_file_through_the_backtest_engine_to_an_exact_result() {
+    let path = scratch_path();
+
+    let ticks = vec![
+        TradeTick {
+            ts_ns: 0,
+            price: 100.0,
+            size: 1.0,
+            side: TickSide::Buy,
+            best_bid: None,
+            best_ask: None,
+        },
+        TradeTick {
+            ts_ns: 1_000_000_000,
+            price: 110.0,
+            size: 1.0,
+            side: TickSide::Sell,
+            best_bid: None,
+            best_ask: None,
+        },
+        TradeTick {
+            ts_ns: 2_000_000_000,
+            price: 105.0,
+            size: 2.0,
+            side: TickSide::Buy,
+            best_bid: None,
+            best_ask: None,
+        },
+        TradeTick {
+            ts_ns: 3_000_000_000,
+            price: 103.0,
+            size: 2.0,
+            side: TickSide::Sell,
+            best_bid: None,
+            best_ask: None,
+        },
+    ];
+
+    let mut writer = BinFileWriter::create(&path).unwrap();
+    for tick in &ticks {
+        writer.write_record(&TradeRecord::from_tick(tick)).unwrap();
+    }
+    writer.flush().unwrap();
+
+    let stream = ReplayEngine::load_bin_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut engine = BacktestEngine::new(FlipFlopAdapter { entry_price: None });
+    let metrics = engine.run(stream);
+
+    let result = metrics.result();
+    assert_eq!(result.total_trades, 2);
+    assert_eq!(result.winning_trades, 1);
+    // Trade 1: (110 - 100) * 1 = 10. Trade 2: (103 - 105) * 2 = -4.
+    assert!((result.total_pnl - 6.0).abs() < 1e-6);
+}
@@ -0,0 +1,113 @@
+//! Replays captured WS payloads from `tests/fixtures/` through each venue's
+//! parser and collector, so a venue changing its schema fails loudly here
+//! instead of silently breaking live parsing.
+
+use rust_trade::base_classes::engine::MarketEvent;
+use rust_trade::collectors::{coinbase as coinbase_collector, kraken as kraken_collector};
+use rust_trade::exchanges::coinbase::{parser as coinbase_parser, CoinbaseHandler};
+use rust_trade::exchanges::kraken::{KrakenFrame, KrakenHandler};
+use rust_trade::models::Side;
+
+#[test]
+fn kraken_book_snapshot_fixture_yields_the_expected_bbo() {
+    let raw = include_str!("fixtures/kraken/book_snapshot.json");
+    let frame = KrakenHandler::parse_frame(raw).unwrap();
+    let mut handler = KrakenHandler::new();
+    let events = kraken_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Bbo(bbo)] => {
+            assert_eq!(bbo.bid, 64250.5);
+            assert_eq!(bbo.ask, 64251.0);
+            assert_eq!(bbo.ts_ns, 1_700_000_000_000);
+        }
+        other => panic!("expected a single Bbo event, got {other:?}"),
+    }
+}
+
+#[test]
+fn kraken_trade_fixture_yields_the_expected_price_qty_and_side() {
+    let raw = include_str!("fixtures/kraken/trade.json");
+    let frame = KrakenHandler::parse_frame(raw).unwrap();
+    assert!(matches!(frame, KrakenFrame::Trade { .. }));
+
+    let mut handler = KrakenHandler::new();
+    let events = kraken_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Trade(trade)] => {
+            assert_eq!(trade.price, 64251.25);
+            assert_eq!(trade.size, 0.75);
+            assert_eq!(trade.side, Side::Sell);
+            assert_eq!(trade.ts_ns, 1_700_000_000_500);
+        }
+        other => panic!("expected a single Trade event, got {other:?}"),
+    }
+}
+
+#[test]
+fn kraken_ticker_fixture_yields_the_expected_last_price() {
+    let raw = include_str!("fixtures/kraken/ticker.json");
+    let frame = KrakenHandler::parse_frame(raw).unwrap();
+    let mut handler = KrakenHandler::new();
+    let events = kraken_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Ticker(ticker)] => {
+            assert_eq!(ticker.last_price, 64251.0);
+            assert_eq!(ticker.ts_ns, 1_700_000_000_900);
+        }
+        other => panic!("expected a single Ticker event, got {other:?}"),
+    }
+}
+
+#[test]
+fn coinbase_level2_fixture_yields_the_expected_bbo() {
+    let raw = include_str!("fixtures/coinbase/level2.json");
+    let frame = coinbase_parser::parse_frame(raw).unwrap();
+    let mut handler = CoinbaseHandler::new();
+    let events = coinbase_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Bbo(bbo)] => {
+            assert_eq!(bbo.bid, 64100.25);
+            assert_eq!(bbo.ask, 64101.0);
+            assert_eq!(bbo.ts_ns, 1_700_000_000_000);
+        }
+        other => panic!("expected a single Bbo event, got {other:?}"),
+    }
+}
+
+#[test]
+fn coinbase_market_trades_fixture_yields_the_expected_price_size_and_side() {
+    let raw = include_str!("fixtures/coinbase/market_trades.json");
+    let frame = coinbase_parser::parse_frame(raw).unwrap();
+    let mut handler = CoinbaseHandler::new();
+    let events = coinbase_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Trade(trade)] => {
+            assert_eq!(trade.price, 64101.5);
+            assert_eq!(trade.size, 0.25);
+            assert_eq!(trade.side, Side::Buy);
+            assert_eq!(trade.ts_ns, 1_700_000_000_500);
+        }
+        other => panic!("expected a single Trade event, got {other:?}"),
+    }
+}
+
+#[test]
+fn coinbase_ticker_fixture_yields_the_expected_last_price() {
+    let raw = include_str!("fixtures/coinbase/ticker.json");
+    let frame = coinbase_parser::parse_frame(raw).unwrap();
+    let mut handler = CoinbaseHandler::new();
+    let events = coinbase_collector::events_for(&mut handler, &frame);
+
+    match events.as_slice() {
+        [MarketEvent::Ticker(ticker)] => {
+            assert_eq!(ticker.last_price, 64101.0);
+            assert_eq!(ticker.ts_ns, 1_700_000_000_900);
+        }
+        other => panic!("expected a single Ticker event, got {other:?}"),
+    }
+}